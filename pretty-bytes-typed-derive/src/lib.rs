@@ -0,0 +1,201 @@
+#![warn(clippy::pedantic, clippy::nursery, rust_2018_idioms)]
+
+//! Derive macro for `pretty-bytes-typed`. Use it through the `derive` feature of that crate instead of depending on this crate directly.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, Ident, LitInt};
+
+/// How a single field should be displayed
+struct FieldOptions {
+    pretty: bool,
+    binary: bool,
+    round: Option<u8>,
+    skip: bool,
+}
+
+fn parse_field_options(field: &Field) -> syn::Result<FieldOptions> {
+    let mut options = FieldOptions {
+        pretty: false,
+        binary: false,
+        round: None,
+        skip: false,
+    };
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("pretty"))
+    {
+        options.pretty = true;
+
+        // A bare `#[pretty]` uses the defaults
+        if matches!(attr.meta, syn::Meta::Path(_)) {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("binary") {
+                options.binary = true;
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("round") {
+                options.round = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+            } else {
+                return Err(meta.error("expected `binary`, `round`, or `skip`"));
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(options)
+}
+
+// Returns every field that isn't skipped, along with how it should be shown
+fn named_fields(input: &DeriveInput) -> syn::Result<Vec<(&Ident, FieldOptions)>> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "PrettyBytesFields can only be derived for structs",
+        ));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "PrettyBytesFields can only be derived for structs with named fields",
+        ));
+    };
+
+    let mut named = Vec::new();
+
+    for field in &fields.named {
+        let options = parse_field_options(field)?;
+
+        if options.skip {
+            continue;
+        }
+
+        let ident = field
+            .ident
+            .as_ref()
+            .expect("named fields always have names");
+
+        named.push((ident, options));
+    }
+
+    Ok(named)
+}
+
+// The value of a field, converted into a prettified value if it's marked with `#[pretty]`
+fn field_value(ident: &Ident, options: &FieldOptions) -> TokenStream {
+    if options.pretty {
+        let function = if options.binary {
+            quote!(::pretty_bytes_typed::pretty_bytes_binary)
+        } else {
+            quote!(::pretty_bytes_typed::pretty_bytes)
+        };
+        let round = options.round.map_or_else(
+            || quote!(::core::option::Option::None),
+            |round| quote!(::core::option::Option::Some(#round)),
+        );
+
+        quote!(#function(::core::convert::Into::<u64>::into(self.#ident), #round))
+    } else {
+        quote!(&self.#ident)
+    }
+}
+
+fn expand_display(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let mut writes = Vec::new();
+
+    for (ident, options) in named_fields(input)? {
+        let separator = if writes.is_empty() { "" } else { ", " };
+        let label = format!("{separator}{ident}: ");
+        let value = field_value(ident, &options);
+
+        writes.push(quote! {
+            f.write_str(#label)?;
+            ::core::fmt::Display::fmt(&#value, f)?;
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #(#writes)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    })
+}
+
+fn expand_serialize(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = named_fields(input)?;
+    let len = fields.len();
+
+    let serialize_fields = fields.iter().map(|(ident, options)| {
+        let key = ident.to_string();
+        let value = field_value(ident, options);
+
+        quote! {
+            __serde::ser::SerializeStruct::serialize_field(&mut state, #key, &#value)?;
+        }
+    });
+
+    let name = &input.ident;
+    let name_str = name.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        const _: () = {
+            use ::pretty_bytes_typed::__private::serde as __serde;
+
+            impl #impl_generics __serde::Serialize for #name #ty_generics #where_clause {
+                fn serialize<__S: __serde::Serializer>(
+                    &self,
+                    serializer: __S,
+                ) -> ::core::result::Result<__S::Ok, __S::Error> {
+                    let mut state = __serde::Serializer::serialize_struct(serializer, #name_str, #len)?;
+                    #(#serialize_fields)*
+                    __serde::ser::SerializeStruct::end(state)
+                }
+            }
+        };
+    })
+}
+
+/// Derive `Display` for a struct, showing each field as `name: value` separated by commas
+///
+/// Fields marked with `#[pretty]` are byte counts (anything that converts into a `u64`), and are shown as prettified byte values. Other fields are shown with their own `Display` implementation.
+///
+/// Attribute options:
+/// - `binary`: use base-2 byte suffixes (KiB, MiB, GiB) instead of base-10 ones
+/// - `round = N`: round to `N` decimal places
+/// - `skip`: leave the field out entirely (works on any field)
+///
+/// Use [`PrettyBytesSerialize`](derive@PrettyBytesSerialize) to serialize the struct the same way.
+#[proc_macro_derive(PrettyBytesFields, attributes(pretty))]
+pub fn derive_pretty_bytes_fields(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_display(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `serde::Serialize` for a struct, serializing fields marked with `#[pretty]` as prettified byte values
+///
+/// Takes the same `#[pretty]` attributes as [`PrettyBytesFields`](derive@PrettyBytesFields). Every other field that isn't skipped has to implement `Serialize` itself.
+#[proc_macro_derive(PrettyBytesSerialize, attributes(pretty))]
+pub fn derive_pretty_bytes_serialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_serialize(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -1,14 +1,57 @@
 #![allow(clippy::module_name_repetitions)]
 
-use crate::util::round_float;
+use crate::util::{
+    adaptive_places, align_decimal_points as align, canonical_mantissa, display_eq,
+    format_with_sign, round_float, write_breakdown, write_fixed_point,
+};
+#[cfg(feature = "translations")]
+use crate::Language;
+use crate::{pretty_bytes, ByteValues, PrettyBytes, SignStyle};
 
 /// Struct that represents prettified byte values (base-2)
-#[derive(Debug, PartialEq, Clone)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+///
+/// Two values are equal if they display the same, even if they were created from different numbers of bytes
+#[derive(Clone)]
 #[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "SerializedPrettyBytesBinary"))]
 pub struct PrettyBytesBinary {
     num: f64,
     suffix: ByteValuesBinary,
+    // The exact number of bytes the value was created from, which can be negative for the signed constructors
+    // Not serialized, so that large values can still be sent to languages without 64-bit integers
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bytes: i128,
+}
+
+// Deserialized values only have the prettified value to go on, so the number of bytes is rebuilt from that
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedPrettyBytesBinary {
+    num: f64,
+    suffix: ByteValuesBinary,
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedPrettyBytesBinary> for PrettyBytesBinary {
+    // Values will often be rounded anyway, so precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn from(SerializedPrettyBytesBinary { num, suffix }: SerializedPrettyBytesBinary) -> Self {
+        Self {
+            num,
+            suffix,
+            bytes: (num * suffix.bytes() as f64).round() as i128,
+        }
+    }
+}
+
+// Compares the prettified value, like the `PartialEq<&str>` implementation
+impl PartialEq for PrettyBytesBinary {
+    // Mantissas are compared exactly, in the same way as the derived implementation
+    #[allow(clippy::float_cmp)]
+    fn eq(&self, other: &Self) -> bool {
+        self.num == other.num && self.suffix == other.suffix
+    }
 }
 
 impl std::fmt::Display for PrettyBytesBinary {
@@ -17,9 +60,113 @@ impl std::fmt::Display for PrettyBytesBinary {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+// Shows the exact number of bytes alongside the prettified value, since the rounded value alone can be misleading
+// The mantissa and unit are shown through `Display` instead
+#[allow(clippy::missing_fields_in_debug)]
+impl std::fmt::Debug for PrettyBytesBinary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrettyBytesBinary")
+            .field("pretty", &format_args!("{self}"))
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+impl PartialEq<&str> for PrettyBytesBinary {
+    fn eq(&self, other: &&str) -> bool {
+        display_eq(self, other)
+    }
+}
+
+/// Compares against the exact number of bytes the value was created from, not the rounded value that is displayed
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_binary;
+/// let prettified = pretty_bytes_binary(1_048_577, Some(0));
+/// assert_eq!(prettified.to_string(), "1 MiB");
+/// assert!(prettified > 1_048_576_u64);
+/// ```
+impl PartialEq<u64> for PrettyBytesBinary {
+    fn eq(&self, other: &u64) -> bool {
+        self.partial_cmp(other) == Some(std::cmp::Ordering::Equal)
+    }
+}
+
+/// Compares against the exact number of bytes the value was created from, not the rounded value that is displayed
+impl PartialOrd<u64> for PrettyBytesBinary {
+    fn partial_cmp(&self, other: &u64) -> Option<std::cmp::Ordering> {
+        Some(self.bytes.cmp(&i128::from(*other)))
+    }
+}
+
+impl PrettyBytesBinary {
+    /// Format the prettified value, showing negative values in the given style
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_bytes_signed_binary, SignStyle};
+    /// let prettified = pretty_bytes_signed_binary(-1_048_576, None);
+    /// assert_eq!(prettified.to_string_with_sign(SignStyle::Parentheses), "(1 MiB)");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_sign(&self, style: SignStyle) -> String {
+        format_with_sign(self.num, self.suffix, style)
+    }
+
+    pub(crate) const fn from_parts(num: f64, suffix: ByteValuesBinary, bytes: i128) -> Self {
+        Self { num, suffix, bytes }
+    }
+
+    #[cfg(any(feature = "bytesize", feature = "byte-unit"))]
+    pub(crate) const fn byte_value(&self) -> i128 {
+        self.bytes
+    }
+}
+
+#[cfg(feature = "translations")]
+impl PrettyBytesBinary {
+    /// Format the prettified value using unit names and the decimal separator of the given language
+    ///
+    /// Use [`to_string_in_long`](Self::to_string_in_long) for long unit names
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_bytes_binary, Language};
+    /// let prettified = pretty_bytes_binary(3_195_498, Some(2));
+    /// assert_eq!(prettified.to_string_in(Language::Russian), "3,05 МиБ");
+    /// ```
+    #[must_use]
+    pub fn to_string_in(&self, language: Language) -> String {
+        let unit = language.binary_units()[self.suffix as usize];
+        format!("{} {unit}", language.format_number(self.num))
+    }
+
+    /// Format the prettified value using long unit names and the decimal separator of the given language
+    ///
+    /// The unit name agrees with the number in front of it, following the plural rules of the language
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_bytes_binary, Language};
+    /// let prettified = pretty_bytes_binary(2_621_440, None);
+    /// assert_eq!(prettified.to_string_in_long(Language::English), "2.5 mebibytes");
+    /// ```
+    #[must_use]
+    pub fn to_string_in_long(&self, language: Language) -> String {
+        let unit = language.binary_long_units()[self.suffix as usize];
+        format!(
+            "{} {unit}{}",
+            language.format_number(self.num),
+            language.plural_ending(self.num)
+        )
+    }
+}
+
+/// Units for base-2 byte values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-enum ByteValuesBinary {
+pub enum ByteValuesBinary {
     B,
     KiB,
     MiB,
@@ -31,7 +178,7 @@ enum ByteValuesBinary {
 
 impl ByteValuesBinary {
     // EiB is the max that can be represented with a u64
-    const UNITS: [Self; 7] = [
+    pub(crate) const UNITS: [Self; 7] = [
         Self::B,
         Self::KiB,
         Self::MiB,
@@ -40,6 +187,12 @@ impl ByteValuesBinary {
         Self::PiB,
         Self::EiB,
     ];
+
+    /// Get the number of bytes in one of this unit
+    #[must_use]
+    pub const fn bytes(self) -> u64 {
+        1024_u64.pow(self as u32)
+    }
 }
 
 /// Convert a byte value to a "prettified" version
@@ -60,11 +213,14 @@ impl ByteValuesBinary {
 // Most likely, values will be too small to experience precision loss, and they will often be rounded anyway
 #[allow(clippy::cast_precision_loss)]
 pub fn pretty_bytes_binary(num: u64, round_places: Option<u8>) -> PrettyBytesBinary {
+    let bytes = num;
+
     // Special handling for 0, because you can't use log on it
     if num == 0 {
         return PrettyBytesBinary {
             num: 0.,
             suffix: ByteValuesBinary::B,
+            bytes: 0,
         };
     }
 
@@ -78,7 +234,39 @@ pub fn pretty_bytes_binary(num: u64, round_places: Option<u8>) -> PrettyBytesBin
 
     let unit = ByteValuesBinary::UNITS[exponent];
 
-    PrettyBytesBinary { num, suffix: unit }
+    PrettyBytesBinary {
+        num,
+        suffix: unit,
+        bytes: i128::from(bytes),
+    }
+}
+
+/// Convert a byte value to a "prettified" version, picking the number of decimal places based on its magnitude
+///
+/// Converts using base-2 byte suffixes (KiB, MiB, GiB). Values below 10 are rounded to 2 decimal places, values below 100 to 1, and larger values to none.
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_adaptive_binary;
+/// assert_eq!(pretty_bytes_adaptive_binary(3_195_498).to_string(), "3.05 MiB");
+/// assert_eq!(pretty_bytes_adaptive_binary(52_428_800).to_string(), "50 MiB");
+/// ```
+// Most likely, values will be too small to experience precision loss, and they will often be rounded anyway
+#[allow(clippy::cast_precision_loss)]
+pub fn pretty_bytes_adaptive_binary(num: u64) -> PrettyBytesBinary {
+    let mut pretty_bytes = pretty_bytes_binary(num, None);
+
+    pretty_bytes.num = round_float(pretty_bytes.num, adaptive_places(pretty_bytes.num));
+
+    // Rounding can carry the mantissa up to the next unit (1023.999 KiB becomes 1024 KiB), so step up to keep it below 1024
+    if pretty_bytes.num >= 1024. && pretty_bytes.suffix != ByteValuesBinary::EiB {
+        pretty_bytes.suffix = ByteValuesBinary::UNITS[pretty_bytes.suffix as usize + 1];
+
+        let mantissa = num as f64 / pretty_bytes.suffix.bytes() as f64;
+        pretty_bytes.num = round_float(mantissa, adaptive_places(mantissa));
+    }
+
+    pretty_bytes
 }
 
 /// Convert a byte value to a "prettified" version, but accepts negative numbers
@@ -99,11 +287,211 @@ pub fn pretty_bytes_signed_binary(num: i64, round_places: Option<u8>) -> PrettyB
 
     if is_negative {
         pretty_bytes.num = -pretty_bytes.num;
+        pretty_bytes.bytes = -pretty_bytes.bytes;
     }
 
     pretty_bytes
 }
 
+/// Format a set of prettified values so that their decimal points line up
+///
+/// Useful for printing values in a column, since every value is padded to the same number of integer and fractional digits
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{align_decimal_points_binary, pretty_bytes_binary};
+/// let aligned = align_decimal_points_binary(&[pretty_bytes_binary(3_195_498, Some(2)), pretty_bytes_binary(1024, None)]);
+/// assert_eq!(aligned, ["3.05 MiB", "1.00 KiB"]);
+/// ```
+#[must_use]
+pub fn align_decimal_points_binary(values: &[PrettyBytesBinary]) -> Vec<String> {
+    align(values.iter().map(|value| (value.num, value.suffix)))
+}
+
+/// Struct that represents a byte value broken down across multiple units (base-2)
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesBreakdownBinary {
+    // Ordered from the smallest unit to the largest
+    parts: [u16; 7],
+}
+
+impl std::fmt::Display for PrettyBytesBreakdownBinary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_breakdown(f, &self.parts, &ByteValuesBinary::UNITS)
+    }
+}
+
+/// Convert a byte value to an exact breakdown across multiple units
+///
+/// Converts using base-2 byte suffixes (KiB, MiB, GiB), skipping any units that are zero
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_breakdown_binary;
+/// let breakdown = pretty_bytes_breakdown_binary(2_621_440);
+/// assert_eq!(breakdown.to_string(), "2 MiB 512 KiB");
+/// ```
+pub fn pretty_bytes_breakdown_binary(mut num: u64) -> PrettyBytesBreakdownBinary {
+    let mut parts = [0; 7];
+
+    for part in &mut parts {
+        *part = (num % 1024) as u16;
+        num /= 1024;
+    }
+
+    PrettyBytesBreakdownBinary { parts }
+}
+
+/// Convert a value from one base-2 unit to another
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{convert_binary, ByteValuesBinary};
+/// assert_eq!(convert_binary(2.5, ByteValuesBinary::MiB, ByteValuesBinary::KiB), 2560.);
+/// ```
+#[must_use]
+// Unit sizes are small enough to be represented exactly
+#[allow(clippy::cast_precision_loss)]
+pub fn convert_binary(value: f64, from: ByteValuesBinary, to: ByteValuesBinary) -> f64 {
+    value * from.bytes() as f64 / to.bytes() as f64
+}
+
+/// Convert a value from a base-10 unit to a base-2 unit
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{convert_to_binary, ByteValues, ByteValuesBinary};
+/// assert_eq!(convert_to_binary(1.024, ByteValues::KB, ByteValuesBinary::KiB), 1.);
+/// ```
+#[must_use]
+// Unit sizes are small enough to be represented exactly
+#[allow(clippy::cast_precision_loss)]
+pub fn convert_to_binary(value: f64, from: ByteValues, to: ByteValuesBinary) -> f64 {
+    value * from.bytes() as f64 / to.bytes() as f64
+}
+
+/// Convert a value from a base-2 unit to a base-10 unit
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{convert_from_binary, ByteValues, ByteValuesBinary};
+/// assert_eq!(convert_from_binary(1., ByteValuesBinary::KiB, ByteValues::KB), 1.024);
+/// ```
+#[must_use]
+// Unit sizes are small enough to be represented exactly
+#[allow(clippy::cast_precision_loss)]
+pub fn convert_from_binary(value: f64, from: ByteValuesBinary, to: ByteValues) -> f64 {
+    value * from.bytes() as f64 / to.bytes() as f64
+}
+
+/// Struct that represents a prettified byte value (base-2) that can be parsed back into exactly the same number of bytes
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesCanonicalBinary {
+    mantissa: u128,
+    places: u8,
+    suffix: ByteValuesBinary,
+}
+
+impl std::fmt::Display for PrettyBytesCanonicalBinary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_fixed_point(f, self.mantissa, self.places)?;
+        write!(f, " {:?}", self.suffix)
+    }
+}
+
+/// Convert a byte value to a "prettified" version that is guaranteed to round-trip through [`parse_bytes`](crate::parse_bytes)
+///
+/// Converts using base-2 byte suffixes (KiB, MiB, GiB), using as few decimal places as possible without losing any bytes
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{parse_bytes, pretty_bytes_canonical_binary};
+/// let prettified = pretty_bytes_canonical_binary(1025);
+/// assert_eq!(prettified.to_string(), "1.001 KiB");
+/// assert_eq!(parse_bytes(&prettified.to_string()), Ok(1025));
+/// ```
+pub fn pretty_bytes_canonical_binary(num: u64) -> PrettyBytesCanonicalBinary {
+    let suffix = ByteValuesBinary::UNITS
+        .into_iter()
+        .rev()
+        .find(|unit| unit.bytes() <= num)
+        .unwrap_or(ByteValuesBinary::B);
+
+    let (mantissa, places) = canonical_mantissa(num, suffix.bytes());
+
+    PrettyBytesCanonicalBinary {
+        mantissa,
+        places,
+        suffix,
+    }
+}
+
+/// Struct that represents prettified byte values (base-2), always displayed with exactly `PLACES` decimal places
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesFixedBinary<const PLACES: u8> {
+    num: f64,
+    suffix: ByteValuesBinary,
+}
+
+impl<const PLACES: u8> std::fmt::Display for PrettyBytesFixedBinary<PLACES> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let places = usize::from(PLACES);
+        write!(f, "{:.places$} {:?}", self.num, self.suffix)
+    }
+}
+
+/// Convert a byte value to a "prettified" version with a fixed number of decimal places
+///
+/// Like [`pretty_bytes_binary`], but the precision is part of the type, and trailing zeros are kept
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_fixed_binary;
+/// let prettified = pretty_bytes_fixed_binary::<2>(3_195_498);
+/// assert_eq!(prettified.to_string(), "3.05 MiB");
+/// ```
+pub fn pretty_bytes_fixed_binary<const PLACES: u8>(num: u64) -> PrettyBytesFixedBinary<PLACES> {
+    let PrettyBytesBinary { num, suffix, .. } = pretty_bytes_binary(num, Some(PLACES));
+
+    PrettyBytesFixedBinary { num, suffix }
+}
+
+/// Struct that represents a prettified byte value in both base-2 and base-10
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesDual {
+    binary: PrettyBytesBinary,
+    decimal: PrettyBytes,
+}
+
+impl std::fmt::Display for PrettyBytesDual {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.binary, self.decimal)
+    }
+}
+
+/// Convert a byte value to a "prettified" version that shows both base-2 and base-10 byte suffixes
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_dual;
+/// let prettified = pretty_bytes_dual(1024_u64.pow(3), Some(2));
+/// assert_eq!(prettified.to_string(), "1 GiB (1.07 GB)");
+/// ```
+pub fn pretty_bytes_dual(num: u64, round_places: Option<u8>) -> PrettyBytesDual {
+    PrettyBytesDual {
+        binary: pretty_bytes_binary(num, round_places),
+        decimal: pretty_bytes(num, round_places),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +505,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 0.,
                 suffix: ByteValuesBinary::B,
+                bytes: 0,
             }
         );
 
@@ -126,6 +515,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 1.,
                 suffix: ByteValuesBinary::B,
+                bytes: i128::from(1024_u64.pow(0)),
             }
         );
 
@@ -134,6 +524,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 1.,
                 suffix: ByteValuesBinary::KiB,
+                bytes: i128::from(1024_u64.pow(1)),
             }
         );
 
@@ -142,6 +533,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 1.,
                 suffix: ByteValuesBinary::MiB,
+                bytes: i128::from(1024_u64.pow(2)),
             }
         );
 
@@ -150,6 +542,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 1.,
                 suffix: ByteValuesBinary::GiB,
+                bytes: i128::from(1024_u64.pow(3)),
             }
         );
 
@@ -158,6 +551,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 1.,
                 suffix: ByteValuesBinary::TiB,
+                bytes: i128::from(1024_u64.pow(4)),
             }
         );
 
@@ -166,6 +560,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 1.,
                 suffix: ByteValuesBinary::PiB,
+                bytes: i128::from(1024_u64.pow(5)),
             }
         );
 
@@ -174,6 +569,7 @@ mod tests {
             PrettyBytesBinary {
                 num: 1.,
                 suffix: ByteValuesBinary::EiB,
+                bytes: i128::from(1024_u64.pow(6)),
             }
         );
 
@@ -183,7 +579,185 @@ mod tests {
             PrettyBytesBinary {
                 num: 4.9,
                 suffix: ByteValuesBinary::KiB,
+                bytes: 5014,
             }
         );
     }
+
+    #[test]
+    fn test_align_decimal_points_binary() {
+        assert_eq!(
+            align_decimal_points_binary(&[
+                pretty_bytes_binary(3_195_498, Some(2)),
+                pretty_bytes_binary(1024 * 512 + 256, Some(1)),
+            ]),
+            ["  3.05 MiB", "512.30 KiB"]
+        );
+    }
+
+    #[test]
+    fn test_comparisons() {
+        assert_eq!(pretty_bytes_binary(1_048_576, Some(2)), "1 MiB");
+        assert_ne!(pretty_bytes_binary(1_048_576, Some(2)), "0 B");
+
+        assert_eq!(pretty_bytes_binary(1_048_576, None), 1_048_576_u64);
+        assert_ne!(pretty_bytes_binary(1_048_576, None), 0_u64);
+
+        assert!(pretty_bytes_binary(1_048_576, None) > 1_048_576_u64 - 1);
+        assert!(pretty_bytes_binary(1_048_576, None) < 1_048_576_u64 + 1);
+        assert!(pretty_bytes_binary(1_048_576, None) >= 1_048_576_u64);
+        assert!(pretty_bytes_signed_binary(-1, None) < 0_u64);
+
+        // Rounding doesn't affect comparisons
+        assert!(pretty_bytes_binary(1_048_577, Some(0)) > 1_048_576_u64);
+        assert_ne!(pretty_bytes_binary(1_048_577, Some(0)), 1_048_576_u64);
+    }
+
+    #[test]
+    fn test_pretty_bytes_breakdown_binary() {
+        assert_eq!(pretty_bytes_breakdown_binary(0).to_string(), "0 B");
+
+        assert_eq!(
+            pretty_bytes_breakdown_binary(2_621_440).to_string(),
+            "2 MiB 512 KiB"
+        );
+
+        assert_eq!(
+            pretty_bytes_breakdown_binary(1024_u64.pow(3) + 1023).to_string(),
+            "1 GiB 1023 B"
+        );
+
+        assert_eq!(
+            pretty_bytes_breakdown_binary(u64::MAX).to_string(),
+            "15 EiB 1023 PiB 1023 TiB 1023 GiB 1023 MiB 1023 KiB 1023 B"
+        );
+    }
+
+    #[test]
+    // All of these results are exactly representable
+    #[allow(clippy::float_cmp)]
+    fn test_convert_binary() {
+        assert_eq!(
+            convert_binary(2.5, ByteValuesBinary::MiB, ByteValuesBinary::KiB),
+            2560.
+        );
+        assert_eq!(
+            convert_binary(512., ByteValuesBinary::KiB, ByteValuesBinary::MiB),
+            0.5
+        );
+        assert_eq!(
+            convert_to_binary(1., ByteValues::MB, ByteValuesBinary::KiB),
+            976.5625
+        );
+        assert_eq!(
+            convert_from_binary(1., ByteValuesBinary::MiB, ByteValues::KB),
+            1048.576
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", pretty_bytes_binary(2_621_440, None)),
+            "PrettyBytesBinary { pretty: 2.5 MiB, bytes: 2621440 }"
+        );
+    }
+
+    #[test]
+    fn test_pretty_bytes_canonical_binary() {
+        assert_eq!(pretty_bytes_canonical_binary(0).to_string(), "0 B");
+        assert_eq!(pretty_bytes_canonical_binary(1023).to_string(), "1023 B");
+        assert_eq!(
+            pretty_bytes_canonical_binary(2_621_440).to_string(),
+            "2.5 MiB"
+        );
+        assert_eq!(pretty_bytes_canonical_binary(1025).to_string(), "1.001 KiB");
+        assert_eq!(
+            pretty_bytes_canonical_binary(1024_u64.pow(4) + 1).to_string(),
+            "1.000000000001 TiB"
+        );
+
+        // Values spread across the whole range of u64 all round-trip
+        let mut num = 1_u64;
+        while let Some(next) = num.checked_mul(3) {
+            for num in [num - 1, num, num + 1, u64::MAX - num] {
+                assert_eq!(
+                    crate::parse_bytes(&pretty_bytes_canonical_binary(num).to_string()),
+                    Ok(num)
+                );
+            }
+            num = next;
+        }
+    }
+
+    #[test]
+    fn test_pretty_bytes_fixed_binary() {
+        assert_eq!(pretty_bytes_fixed_binary::<2>(1024).to_string(), "1.00 KiB");
+        assert_eq!(
+            pretty_bytes_fixed_binary::<2>(3_195_498).to_string(),
+            "3.05 MiB"
+        );
+        assert_eq!(
+            pretty_bytes_fixed_binary::<1>(2_621_440).to_string(),
+            "2.5 MiB"
+        );
+    }
+
+    #[test]
+    fn test_pretty_bytes_dual() {
+        assert_eq!(pretty_bytes_dual(0, None).to_string(), "0 B (0 B)");
+        assert_eq!(pretty_bytes_dual(1000, None).to_string(), "1000 B (1 KB)");
+        assert_eq!(
+            pretty_bytes_dual(1024_u64.pow(3), Some(2)).to_string(),
+            "1 GiB (1.07 GB)"
+        );
+        assert_eq!(
+            pretty_bytes_dual(2_621_440, Some(1)).to_string(),
+            "2.5 MiB (2.6 MB)"
+        );
+    }
+
+    #[test]
+    fn test_pretty_bytes_adaptive_binary() {
+        assert_eq!(
+            pretty_bytes_adaptive_binary(3_195_498).to_string(),
+            "3.05 MiB"
+        );
+        assert_eq!(
+            pretty_bytes_adaptive_binary(52_428_800).to_string(),
+            "50 MiB"
+        );
+        assert_eq!(
+            pretty_bytes_adaptive_binary(53_000_000).to_string(),
+            "50.5 MiB"
+        );
+        assert_eq!(
+            pretty_bytes_adaptive_binary(1024_u64.pow(2) * 999).to_string(),
+            "999 MiB"
+        );
+
+        // Rounding up to the next unit
+        assert_eq!(
+            pretty_bytes_adaptive_binary(1024 * 1024 - 1).to_string(),
+            "1 MiB"
+        );
+        assert_eq!(pretty_bytes_adaptive_binary(1023).to_string(), "1023 B");
+        assert_eq!(
+            pretty_bytes_adaptive_binary(1024 * 1023 + 511).to_string(),
+            "1023 KiB"
+        );
+    }
+
+    #[test]
+    fn test_to_string_with_sign() {
+        assert_eq!(
+            pretty_bytes_signed_binary(-2_621_440, None)
+                .to_string_with_sign(SignStyle::Parentheses),
+            "(2.5 MiB)"
+        );
+        assert_eq!(
+            pretty_bytes_signed_binary(2_621_440, None).to_string_with_sign(SignStyle::Parentheses),
+            "2.5 MiB"
+        );
+    }
 }
@@ -0,0 +1,238 @@
+use crate::{
+    pretty_bytes, pretty_bytes_binary, util::round_float, PrettyBytes, PrettyBytesBinary, SignStyle,
+};
+
+/// Struct that represents a prettified change between two byte values (base-10)
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyChange {
+    negative: bool,
+    delta: PrettyBytes,
+    percent: Option<f64>,
+}
+
+impl std::fmt::Display for PrettyChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_change(
+            f,
+            self.negative,
+            &self.delta,
+            self.percent,
+            SignStyle::Minus,
+        )
+    }
+}
+
+impl PrettyChange {
+    /// Format the change, showing decreases in the given style
+    ///
+    /// With [`SignStyle::Parentheses`], decreases are shown in parentheses and neither the difference nor the percentage gets a sign
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_change, SignStyle};
+    /// let change = pretty_change(17_500_000_000, 15_400_000_000, Some(1));
+    /// assert_eq!(change.to_string_with_sign(SignStyle::Parentheses), "(2.1 GB) (12%)");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_sign(&self, style: SignStyle) -> String {
+        let mut string = String::new();
+        // Writing to a `String` can't fail
+        let _ = write_change(&mut string, self.negative, &self.delta, self.percent, style);
+        string
+    }
+}
+
+/// Struct that represents a prettified change between two byte values (base-2)
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyChangeBinary {
+    negative: bool,
+    delta: PrettyBytesBinary,
+    percent: Option<f64>,
+}
+
+impl std::fmt::Display for PrettyChangeBinary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_change(
+            f,
+            self.negative,
+            &self.delta,
+            self.percent,
+            SignStyle::Minus,
+        )
+    }
+}
+
+impl PrettyChangeBinary {
+    /// Format the change, showing decreases in the given style
+    ///
+    /// With [`SignStyle::Parentheses`], decreases are shown in parentheses and neither the difference nor the percentage gets a sign
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_change_binary, SignStyle};
+    /// let change = pretty_change_binary(2048, 1024, None);
+    /// assert_eq!(change.to_string_with_sign(SignStyle::Parentheses), "(1 KiB) (50%)");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_sign(&self, style: SignStyle) -> String {
+        let mut string = String::new();
+        // Writing to a `String` can't fail
+        let _ = write_change(&mut string, self.negative, &self.delta, self.percent, style);
+        string
+    }
+}
+
+fn write_change(
+    f: &mut impl std::fmt::Write,
+    negative: bool,
+    delta: &(impl std::fmt::Display + PartialEq<u64>),
+    percent: Option<f64>,
+    style: SignStyle,
+) -> std::fmt::Result {
+    if style == SignStyle::Parentheses {
+        if negative {
+            write!(f, "({delta})")?;
+        } else {
+            write!(f, "{delta}")?;
+        }
+
+        if let Some(percent) = percent {
+            write!(f, " ({percent}%)")?;
+        }
+
+        return Ok(());
+    }
+
+    let sign = if negative {
+        "-"
+    } else if *delta == 0 {
+        ""
+    } else {
+        "+"
+    };
+
+    write!(f, "{sign}{delta}")?;
+
+    // There's no meaningful percentage when starting from 0
+    if let Some(percent) = percent {
+        write!(f, " ({sign}{percent}%)")?;
+    }
+
+    Ok(())
+}
+
+// Only byte counts above 2^53 lose precision, and the error that causes is far smaller than the rounding applied to the percentage
+#[allow(clippy::cast_precision_loss)]
+fn percent_change(old: u64, new: u64, round_places: Option<u8>) -> Option<f64> {
+    if old == 0 {
+        return None;
+    }
+
+    let percent = old.abs_diff(new) as f64 / old as f64 * 100.;
+
+    Some(round_places.map_or(percent, |round_places| round_float(percent, round_places)))
+}
+
+/// Describe the change between two byte values, as both a "prettified" difference and a percentage
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB), and rounds both the difference and the percentage to `round_places`.
+/// The percentage is left out when `old` is 0.
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_change;
+/// let change = pretty_change(3_544_000_000, 3_664_500_000, Some(1));
+/// assert_eq!(change.to_string(), "+120.5 MB (+3.4%)");
+///
+/// let change = pretty_change(17_500_000_000, 15_400_000_000, Some(1));
+/// assert_eq!(change.to_string(), "-2.1 GB (-12%)");
+/// ```
+pub fn pretty_change(old: u64, new: u64, round_places: Option<u8>) -> PrettyChange {
+    PrettyChange {
+        negative: new < old,
+        delta: pretty_bytes(old.abs_diff(new), round_places),
+        percent: percent_change(old, new, round_places),
+    }
+}
+
+/// Describe the change between two byte values, as both a "prettified" difference and a percentage
+///
+/// Converts using base-2 byte suffixes (KiB, MiB, GiB), and rounds both the difference and the percentage to `round_places`.
+/// The percentage is left out when `old` is 0.
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_change_binary;
+/// let change = pretty_change_binary(1_048_576, 1_572_864, None);
+/// assert_eq!(change.to_string(), "+512 KiB (+50%)");
+/// ```
+pub fn pretty_change_binary(old: u64, new: u64, round_places: Option<u8>) -> PrettyChangeBinary {
+    PrettyChangeBinary {
+        negative: new < old,
+        delta: pretty_bytes_binary(old.abs_diff(new), round_places),
+        percent: percent_change(old, new, round_places),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_change() {
+        assert_eq!(
+            pretty_change(3_544_000_000, 3_664_500_000, Some(1)).to_string(),
+            "+120.5 MB (+3.4%)"
+        );
+        assert_eq!(
+            pretty_change(17_500_000_000, 15_400_000_000, Some(1)).to_string(),
+            "-2.1 GB (-12%)"
+        );
+        assert_eq!(pretty_change(5_000, 5_000, None).to_string(), "0 B (0%)");
+        assert_eq!(pretty_change(0, 2_000_000, None).to_string(), "+2 MB");
+        assert_eq!(pretty_change(1_000, 0, None).to_string(), "-1 KB (-100%)");
+        assert_eq!(pretty_change(0, u64::MAX, Some(0)).to_string(), "+18 EB");
+    }
+
+    #[test]
+    fn test_to_string_with_sign() {
+        assert_eq!(
+            pretty_change(17_500_000_000, 15_400_000_000, Some(1))
+                .to_string_with_sign(SignStyle::Parentheses),
+            "(2.1 GB) (12%)"
+        );
+        assert_eq!(
+            pretty_change(3_544_000_000, 3_664_500_000, Some(1))
+                .to_string_with_sign(SignStyle::Parentheses),
+            "120.5 MB (3.4%)"
+        );
+        assert_eq!(
+            pretty_change(1_000, 0, None).to_string_with_sign(SignStyle::Minus),
+            "-1 KB (-100%)"
+        );
+        assert_eq!(
+            pretty_change(0, 0, None).to_string_with_sign(SignStyle::Parentheses),
+            "0 B"
+        );
+        assert_eq!(
+            pretty_change_binary(2048, 1024, None).to_string_with_sign(SignStyle::Parentheses),
+            "(1 KiB) (50%)"
+        );
+    }
+
+    #[test]
+    fn test_pretty_change_binary() {
+        assert_eq!(
+            pretty_change_binary(1_048_576, 1_572_864, None).to_string(),
+            "+512 KiB (+50%)"
+        );
+        assert_eq!(
+            pretty_change_binary(2048, 1024, None).to_string(),
+            "-1 KiB (-50%)"
+        );
+    }
+}
@@ -1,12 +1,55 @@
-use crate::util::round_float;
+use crate::util::{
+    adaptive_places, align_decimal_points as align, canonical_mantissa, display_eq,
+    format_with_sign, round_float, write_breakdown, write_fixed_point,
+};
+#[cfg(feature = "translations")]
+use crate::Language;
+use crate::SignStyle;
 
 /// Struct that represents prettified byte values (base-10)
-#[derive(PartialEq, Debug, Clone)]
+///
+/// Two values are equal if they display the same, even if they were created from different numbers of bytes
+#[derive(Clone)]
 #[must_use]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "SerializedPrettyBytes"))]
 pub struct PrettyBytes {
     num: f64,
     suffix: ByteValues,
+    // The exact number of bytes the value was created from, which can be negative for the signed constructors
+    // Not serialized, so that large values can still be sent to languages without 64-bit integers
+    #[cfg_attr(feature = "serde", serde(skip))]
+    bytes: i128,
+}
+
+// Deserialized values only have the prettified value to go on, so the number of bytes is rebuilt from that
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedPrettyBytes {
+    num: f64,
+    suffix: ByteValues,
+}
+
+#[cfg(feature = "serde")]
+impl From<SerializedPrettyBytes> for PrettyBytes {
+    // Values will often be rounded anyway, so precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn from(SerializedPrettyBytes { num, suffix }: SerializedPrettyBytes) -> Self {
+        Self {
+            num,
+            suffix,
+            bytes: (num * suffix.bytes() as f64).round() as i128,
+        }
+    }
+}
+
+// Compares the prettified value, like the `PartialEq<&str>` implementation
+impl PartialEq for PrettyBytes {
+    // Mantissas are compared exactly, in the same way as the derived implementation
+    #[allow(clippy::float_cmp)]
+    fn eq(&self, other: &Self) -> bool {
+        self.num == other.num && self.suffix == other.suffix
+    }
 }
 
 impl std::fmt::Display for PrettyBytes {
@@ -15,9 +58,113 @@ impl std::fmt::Display for PrettyBytes {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+// Shows the exact number of bytes alongside the prettified value, since the rounded value alone can be misleading
+// The mantissa and unit are shown through `Display` instead
+#[allow(clippy::missing_fields_in_debug)]
+impl std::fmt::Debug for PrettyBytes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PrettyBytes")
+            .field("pretty", &format_args!("{self}"))
+            .field("bytes", &self.bytes)
+            .finish()
+    }
+}
+
+impl PartialEq<&str> for PrettyBytes {
+    fn eq(&self, other: &&str) -> bool {
+        display_eq(self, other)
+    }
+}
+
+/// Compares against the exact number of bytes the value was created from, not the rounded value that is displayed
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes;
+/// let prettified = pretty_bytes(1_000_001, Some(0));
+/// assert_eq!(prettified.to_string(), "1 MB");
+/// assert!(prettified > 1_000_000_u64);
+/// ```
+impl PartialEq<u64> for PrettyBytes {
+    fn eq(&self, other: &u64) -> bool {
+        self.partial_cmp(other) == Some(std::cmp::Ordering::Equal)
+    }
+}
+
+/// Compares against the exact number of bytes the value was created from, not the rounded value that is displayed
+impl PartialOrd<u64> for PrettyBytes {
+    fn partial_cmp(&self, other: &u64) -> Option<std::cmp::Ordering> {
+        Some(self.bytes.cmp(&i128::from(*other)))
+    }
+}
+
+impl PrettyBytes {
+    /// Format the prettified value, showing negative values in the given style
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_bytes_signed, SignStyle};
+    /// let prettified = pretty_bytes_signed(-1_200_000, None);
+    /// assert_eq!(prettified.to_string_with_sign(SignStyle::Parentheses), "(1.2 MB)");
+    /// ```
+    #[must_use]
+    pub fn to_string_with_sign(&self, style: SignStyle) -> String {
+        format_with_sign(self.num, self.suffix, style)
+    }
+
+    pub(crate) const fn from_parts(num: f64, suffix: ByteValues, bytes: i128) -> Self {
+        Self { num, suffix, bytes }
+    }
+
+    #[cfg(any(feature = "bytesize", feature = "byte-unit"))]
+    pub(crate) const fn byte_value(&self) -> i128 {
+        self.bytes
+    }
+}
+
+#[cfg(feature = "translations")]
+impl PrettyBytes {
+    /// Format the prettified value using unit names and the decimal separator of the given language
+    ///
+    /// Use [`to_string_in_long`](Self::to_string_in_long) for long unit names
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_bytes, Language};
+    /// let prettified = pretty_bytes(3_564_234, Some(2));
+    /// assert_eq!(prettified.to_string_in(Language::French), "3,56 Mo");
+    /// ```
+    #[must_use]
+    pub fn to_string_in(&self, language: Language) -> String {
+        let unit = language.decimal_units()[self.suffix as usize];
+        format!("{} {unit}", language.format_number(self.num))
+    }
+
+    /// Format the prettified value using long unit names and the decimal separator of the given language
+    ///
+    /// The unit name agrees with the number in front of it, following the plural rules of the language
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::{pretty_bytes, Language};
+    /// let prettified = pretty_bytes(3_564_234, Some(2));
+    /// assert_eq!(prettified.to_string_in_long(Language::German), "3,56 Megabyte");
+    /// ```
+    #[must_use]
+    pub fn to_string_in_long(&self, language: Language) -> String {
+        let unit = language.decimal_long_units()[self.suffix as usize];
+        format!(
+            "{} {unit}{}",
+            language.format_number(self.num),
+            language.plural_ending(self.num)
+        )
+    }
+}
+
+/// Units for base-10 byte values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-enum ByteValues {
+pub enum ByteValues {
     B,
     KB,
     MB,
@@ -29,7 +176,7 @@ enum ByteValues {
 
 impl ByteValues {
     // EB is the max that can be represented with a u64
-    const UNITS: [Self; 7] = [
+    pub(crate) const UNITS: [Self; 7] = [
         Self::B,
         Self::KB,
         Self::MB,
@@ -38,6 +185,12 @@ impl ByteValues {
         Self::PB,
         Self::EB,
     ];
+
+    /// Get the number of bytes in one of this unit
+    #[must_use]
+    pub const fn bytes(self) -> u64 {
+        1000_u64.pow(self as u32)
+    }
 }
 
 /// Convert a byte value to a "prettified" version
@@ -58,11 +211,14 @@ impl ByteValues {
 // Most likely, values will be too small to experience precision loss, and they will often be rounded anyway
 #[allow(clippy::cast_precision_loss)]
 pub fn pretty_bytes(num: u64, round_places: Option<u8>) -> PrettyBytes {
+    let bytes = num;
+
     // Special handling for 0, because you can't use log10 on it
     if num == 0 {
         return PrettyBytes {
             num: 0.,
             suffix: ByteValues::B,
+            bytes: 0,
         };
     }
 
@@ -76,7 +232,66 @@ pub fn pretty_bytes(num: u64, round_places: Option<u8>) -> PrettyBytes {
 
     let unit = ByteValues::UNITS[exponent];
 
-    PrettyBytes { num, suffix: unit }
+    PrettyBytes {
+        num,
+        suffix: unit,
+        bytes: i128::from(bytes),
+    }
+}
+
+// Used for values that don't have to be a whole number of bytes, such as rates
+// `pub(crate)` keeps it out of the glob re-export in lib.rs
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) fn pretty_bytes_float(num: f64, round_places: Option<u8>) -> PrettyBytes {
+    let bytes = num.round() as i128;
+
+    let mut exponent = 0;
+    while exponent < ByteValues::UNITS.len() - 1 && num >= 1000_f64.powi(exponent as i32 + 1) {
+        exponent += 1;
+    }
+
+    let mut num = num / 1000_f64.powi(exponent as i32);
+
+    if let Some(round_places) = round_places {
+        num = round_float(num, round_places);
+    }
+
+    let unit = ByteValues::UNITS[exponent];
+
+    PrettyBytes {
+        num,
+        suffix: unit,
+        bytes,
+    }
+}
+
+/// Convert a byte value to a "prettified" version, picking the number of decimal places based on its magnitude
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB). Values below 10 are rounded to 2 decimal places, values below 100 to 1, and larger values to none.
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_adaptive;
+/// assert_eq!(pretty_bytes_adaptive(1_234_567_890).to_string(), "1.23 GB");
+/// assert_eq!(pretty_bytes_adaptive(12_345_678_901).to_string(), "12.3 GB");
+/// assert_eq!(pretty_bytes_adaptive(123_456_789_012).to_string(), "123 GB");
+/// ```
+// Most likely, values will be too small to experience precision loss, and they will often be rounded anyway
+#[allow(clippy::cast_precision_loss)]
+pub fn pretty_bytes_adaptive(num: u64) -> PrettyBytes {
+    let mut pretty_bytes = pretty_bytes(num, None);
+
+    pretty_bytes.num = round_float(pretty_bytes.num, adaptive_places(pretty_bytes.num));
+
+    // Rounding can carry the mantissa up to the next unit (999.999 KB becomes 1000 KB), so step up to keep it below 1000
+    if pretty_bytes.num >= 1000. && pretty_bytes.suffix != ByteValues::EB {
+        pretty_bytes.suffix = ByteValues::UNITS[pretty_bytes.suffix as usize + 1];
+
+        let mantissa = num as f64 / pretty_bytes.suffix.bytes() as f64;
+        pretty_bytes.num = round_float(mantissa, adaptive_places(mantissa));
+    }
+
+    pretty_bytes
 }
 
 /// Convert a byte value to a "prettified" version, but accepts negative numbers
@@ -97,11 +312,153 @@ pub fn pretty_bytes_signed(num: i64, round_places: Option<u8>) -> PrettyBytes {
 
     if is_negative {
         pretty_bytes.num = -pretty_bytes.num;
+        pretty_bytes.bytes = -pretty_bytes.bytes;
     }
 
     pretty_bytes
 }
 
+/// Format a set of prettified values so that their decimal points line up
+///
+/// Useful for printing values in a column, since every value is padded to the same number of integer and fractional digits
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{align_decimal_points, pretty_bytes};
+/// let aligned = align_decimal_points(&[pretty_bytes(3_564_234, Some(2)), pretty_bytes(745_200, Some(2))]);
+/// assert_eq!(aligned, ["  3.56 MB", "745.20 KB"]);
+/// ```
+#[must_use]
+pub fn align_decimal_points(values: &[PrettyBytes]) -> Vec<String> {
+    align(values.iter().map(|value| (value.num, value.suffix)))
+}
+
+/// Struct that represents a byte value broken down across multiple units (base-10)
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesBreakdown {
+    // Ordered from the smallest unit to the largest
+    parts: [u16; 7],
+}
+
+impl std::fmt::Display for PrettyBytesBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_breakdown(f, &self.parts, &ByteValues::UNITS)
+    }
+}
+
+/// Convert a byte value to an exact breakdown across multiple units
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB), skipping any units that are zero
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_breakdown;
+/// let breakdown = pretty_bytes_breakdown(3_564_234);
+/// assert_eq!(breakdown.to_string(), "3 MB 564 KB 234 B");
+/// ```
+pub fn pretty_bytes_breakdown(mut num: u64) -> PrettyBytesBreakdown {
+    let mut parts = [0; 7];
+
+    for part in &mut parts {
+        *part = (num % 1000) as u16;
+        num /= 1000;
+    }
+
+    PrettyBytesBreakdown { parts }
+}
+
+/// Convert a value from one base-10 unit to another
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{convert, ByteValues};
+/// assert_eq!(convert(2.5, ByteValues::MB, ByteValues::KB), 2500.);
+/// ```
+#[must_use]
+// Unit sizes are small enough to be represented exactly
+#[allow(clippy::cast_precision_loss)]
+pub fn convert(value: f64, from: ByteValues, to: ByteValues) -> f64 {
+    value * from.bytes() as f64 / to.bytes() as f64
+}
+
+/// Struct that represents a prettified byte value (base-10) that can be parsed back into exactly the same number of bytes
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesCanonical {
+    mantissa: u128,
+    places: u8,
+    suffix: ByteValues,
+}
+
+impl std::fmt::Display for PrettyBytesCanonical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_fixed_point(f, self.mantissa, self.places)?;
+        write!(f, " {:?}", self.suffix)
+    }
+}
+
+/// Convert a byte value to a "prettified" version that is guaranteed to round-trip through [`parse_bytes`](crate::parse_bytes)
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB), using as few decimal places as possible without losing any bytes
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{parse_bytes, pretty_bytes_canonical};
+/// let prettified = pretty_bytes_canonical(3_564_234);
+/// assert_eq!(prettified.to_string(), "3.564234 MB");
+/// assert_eq!(parse_bytes(&prettified.to_string()), Ok(3_564_234));
+/// ```
+pub fn pretty_bytes_canonical(num: u64) -> PrettyBytesCanonical {
+    let suffix = ByteValues::UNITS
+        .into_iter()
+        .rev()
+        .find(|unit| unit.bytes() <= num)
+        .unwrap_or(ByteValues::B);
+
+    let (mantissa, places) = canonical_mantissa(num, suffix.bytes());
+
+    PrettyBytesCanonical {
+        mantissa,
+        places,
+        suffix,
+    }
+}
+
+/// Struct that represents prettified byte values (base-10), always displayed with exactly `PLACES` decimal places
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesFixed<const PLACES: u8> {
+    num: f64,
+    suffix: ByteValues,
+}
+
+impl<const PLACES: u8> std::fmt::Display for PrettyBytesFixed<PLACES> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let places = usize::from(PLACES);
+        write!(f, "{:.places$} {:?}", self.num, self.suffix)
+    }
+}
+
+/// Convert a byte value to a "prettified" version with a fixed number of decimal places
+///
+/// Like [`pretty_bytes`], but the precision is part of the type, and trailing zeros are kept
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_fixed;
+/// let prettified = pretty_bytes_fixed::<2>(2_000_000);
+/// assert_eq!(prettified.to_string(), "2.00 MB");
+/// ```
+pub fn pretty_bytes_fixed<const PLACES: u8>(num: u64) -> PrettyBytesFixed<PLACES> {
+    let PrettyBytes { num, suffix, .. } = pretty_bytes(num, Some(PLACES));
+
+    PrettyBytesFixed { num, suffix }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -115,6 +472,7 @@ mod tests {
             PrettyBytes {
                 num: 0.,
                 suffix: ByteValues::B,
+                bytes: 0,
             }
         );
 
@@ -123,6 +481,7 @@ mod tests {
             PrettyBytes {
                 num: 5.43,
                 suffix: ByteValues::KB,
+                bytes: 5_430,
             }
         );
 
@@ -132,6 +491,7 @@ mod tests {
             PrettyBytes {
                 num: 1.,
                 suffix: ByteValues::B,
+                bytes: i128::from(1000_u64.pow(0)),
             }
         );
 
@@ -140,6 +500,7 @@ mod tests {
             PrettyBytes {
                 num: 1.,
                 suffix: ByteValues::KB,
+                bytes: i128::from(1000_u64.pow(1)),
             }
         );
 
@@ -148,6 +509,7 @@ mod tests {
             PrettyBytes {
                 num: 1.,
                 suffix: ByteValues::MB,
+                bytes: i128::from(1000_u64.pow(2)),
             }
         );
 
@@ -156,6 +518,7 @@ mod tests {
             PrettyBytes {
                 num: 1.,
                 suffix: ByteValues::GB,
+                bytes: i128::from(1000_u64.pow(3)),
             }
         );
 
@@ -164,6 +527,7 @@ mod tests {
             PrettyBytes {
                 num: 1.,
                 suffix: ByteValues::TB,
+                bytes: i128::from(1000_u64.pow(4)),
             }
         );
 
@@ -172,6 +536,7 @@ mod tests {
             PrettyBytes {
                 num: 1.,
                 suffix: ByteValues::PB,
+                bytes: i128::from(1000_u64.pow(5)),
             }
         );
 
@@ -180,6 +545,7 @@ mod tests {
             PrettyBytes {
                 num: 1.,
                 suffix: ByteValues::EB,
+                bytes: i128::from(1000_u64.pow(6)),
             }
         );
 
@@ -189,6 +555,7 @@ mod tests {
             PrettyBytes {
                 num: 18.,
                 suffix: ByteValues::EB,
+                bytes: 18_000_000_000_000_000_000,
             }
         );
 
@@ -198,6 +565,7 @@ mod tests {
             PrettyBytes {
                 num: 50.06,
                 suffix: ByteValues::KB,
+                bytes: 50060,
             }
         );
 
@@ -206,6 +574,7 @@ mod tests {
             PrettyBytes {
                 num: 736.532_432,
                 suffix: ByteValues::MB,
+                bytes: 736_532_432,
             }
         );
 
@@ -215,6 +584,7 @@ mod tests {
             PrettyBytes {
                 num: 5.,
                 suffix: ByteValues::KB,
+                bytes: 5003,
             }
         );
 
@@ -223,6 +593,7 @@ mod tests {
             PrettyBytes {
                 num: 8.45,
                 suffix: ByteValues::MB,
+                bytes: 8_452_020,
             }
         );
 
@@ -231,7 +602,188 @@ mod tests {
             PrettyBytes {
                 num: 56.,
                 suffix: ByteValues::KB,
+                bytes: 55_700,
+            }
+        );
+    }
+
+    #[test]
+    fn test_align_decimal_points() {
+        assert_eq!(
+            align_decimal_points(&[
+                pretty_bytes(3_564_234, Some(2)),
+                pretty_bytes(745_200, Some(2)),
+                pretty_bytes(5, None),
+            ]),
+            ["  3.56 MB", "745.20 KB", "  5.00 B"]
+        );
+
+        assert_eq!(
+            align_decimal_points(&[pretty_bytes(2_000_000, None), pretty_bytes(55_700, Some(0))]),
+            [" 2 MB", "56 KB"]
+        );
+
+        assert_eq!(
+            align_decimal_points(&[
+                pretty_bytes_signed(-1_500, None),
+                pretty_bytes(20_250, None)
+            ]),
+            ["-1.50 KB", "20.25 KB"]
+        );
+
+        assert!(align_decimal_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_comparisons() {
+        // Equality between values ignores the exact number of bytes
+        assert_eq!(
+            pretty_bytes(1_000_001, Some(0)),
+            pretty_bytes(1_000_000, Some(0))
+        );
+        assert_ne!(pretty_bytes(1_000_001, None), pretty_bytes(1_000_000, None));
+
+        assert_eq!(pretty_bytes(3_560_000, Some(2)), "3.56 MB");
+        assert_ne!(pretty_bytes(3_560_000, Some(2)), "0 B");
+
+        assert_eq!(pretty_bytes(3_560_000, None), 3_560_000_u64);
+        assert_ne!(pretty_bytes(3_560_000, None), 0_u64);
+
+        assert!(pretty_bytes(3_560_000, None) > 3_560_000_u64 - 1);
+        assert!(pretty_bytes(3_560_000, None) < 3_560_000_u64 + 1);
+        assert!(pretty_bytes(3_560_000, None) >= 3_560_000_u64);
+        assert!(pretty_bytes_signed(-1, None) < 0_u64);
+
+        // Rounding doesn't affect comparisons
+        assert!(pretty_bytes(1_000_001, Some(0)) > 1_000_000_u64);
+        assert_ne!(pretty_bytes(1_000_001, Some(0)), 1_000_000_u64);
+    }
+
+    #[test]
+    fn test_pretty_bytes_breakdown() {
+        assert_eq!(pretty_bytes_breakdown(0).to_string(), "0 B");
+
+        assert_eq!(pretty_bytes_breakdown(999).to_string(), "999 B");
+
+        assert_eq!(pretty_bytes_breakdown(2_000_000).to_string(), "2 MB");
+
+        assert_eq!(
+            pretty_bytes_breakdown(3_564_234).to_string(),
+            "3 MB 564 KB 234 B"
+        );
+
+        assert_eq!(
+            pretty_bytes_breakdown(1_000_000_001).to_string(),
+            "1 GB 1 B"
+        );
+
+        assert_eq!(
+            pretty_bytes_breakdown(u64::MAX).to_string(),
+            "18 EB 446 PB 744 TB 73 GB 709 MB 551 KB 615 B"
+        );
+    }
+
+    #[test]
+    // All of these results are exactly representable
+    #[allow(clippy::float_cmp)]
+    fn test_convert() {
+        assert_eq!(convert(2.5, ByteValues::MB, ByteValues::KB), 2500.);
+        assert_eq!(convert(2500., ByteValues::KB, ByteValues::MB), 2.5);
+        assert_eq!(convert(1., ByteValues::EB, ByteValues::B), 1e18);
+        assert_eq!(convert(3., ByteValues::GB, ByteValues::GB), 3.);
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", pretty_bytes(3_564_234, None)),
+            "PrettyBytes { pretty: 3.564234 MB, bytes: 3564234 }"
+        );
+
+        assert_eq!(
+            format!("{:?}", pretty_bytes(u64::MAX, Some(2))),
+            "PrettyBytes { pretty: 18.45 EB, bytes: 18446744073709551615 }"
+        );
+
+        assert_eq!(
+            format!("{:?}", pretty_bytes_signed(-3_564_234, Some(2))),
+            "PrettyBytes { pretty: -3.56 MB, bytes: -3564234 }"
+        );
+    }
+
+    #[test]
+    fn test_pretty_bytes_canonical() {
+        assert_eq!(pretty_bytes_canonical(0).to_string(), "0 B");
+        assert_eq!(pretty_bytes_canonical(999).to_string(), "999 B");
+        assert_eq!(pretty_bytes_canonical(2_000_000).to_string(), "2 MB");
+        assert_eq!(pretty_bytes_canonical(3_564_234).to_string(), "3.564234 MB");
+        assert_eq!(
+            pretty_bytes_canonical(1_000_000_001).to_string(),
+            "1.000000001 GB"
+        );
+        assert_eq!(
+            pretty_bytes_canonical(u64::MAX).to_string(),
+            "18.446744073709551615 EB"
+        );
+
+        // Values spread across the whole range of u64 all round-trip
+        let mut num = 1_u64;
+        while let Some(next) = num.checked_mul(3) {
+            for num in [num - 1, num, num + 1, u64::MAX - num] {
+                assert_eq!(
+                    crate::parse_bytes(&pretty_bytes_canonical(num).to_string()),
+                    Ok(num)
+                );
             }
+            num = next;
+        }
+    }
+
+    #[test]
+    fn test_pretty_bytes_fixed() {
+        assert_eq!(pretty_bytes_fixed::<2>(0).to_string(), "0.00 B");
+        assert_eq!(pretty_bytes_fixed::<2>(2_000_000).to_string(), "2.00 MB");
+        assert_eq!(pretty_bytes_fixed::<2>(3_564_234).to_string(), "3.56 MB");
+        assert_eq!(pretty_bytes_fixed::<1>(5_430).to_string(), "5.4 KB");
+        assert_eq!(pretty_bytes_fixed::<0>(55_700).to_string(), "56 KB");
+        assert_eq!(pretty_bytes_fixed::<3>(8_452_020).to_string(), "8.452 MB");
+    }
+
+    #[test]
+    fn test_pretty_bytes_adaptive() {
+        assert_eq!(pretty_bytes_adaptive(0).to_string(), "0 B");
+        assert_eq!(pretty_bytes_adaptive(999).to_string(), "999 B");
+        assert_eq!(pretty_bytes_adaptive(1_234_567_890).to_string(), "1.23 GB");
+        assert_eq!(pretty_bytes_adaptive(12_345_678_901).to_string(), "12.3 GB");
+        assert_eq!(pretty_bytes_adaptive(123_456_789_012).to_string(), "123 GB");
+        assert_eq!(pretty_bytes_adaptive(5_430).to_string(), "5.43 KB");
+        assert_eq!(pretty_bytes_adaptive(50_060).to_string(), "50.1 KB");
+        assert_eq!(pretty_bytes_adaptive(736_532_432).to_string(), "737 MB");
+
+        // Rounding up to the next unit
+        assert_eq!(pretty_bytes_adaptive(999_999).to_string(), "1 MB");
+        assert_eq!(pretty_bytes_adaptive(999_499).to_string(), "999 KB");
+        assert_eq!(pretty_bytes_adaptive(999_999_999_999).to_string(), "1 TB");
+        assert_eq!(pretty_bytes_adaptive(u64::MAX).to_string(), "18.4 EB");
+    }
+
+    #[test]
+    fn test_to_string_with_sign() {
+        assert_eq!(
+            pretty_bytes_signed(-1_200_000, None).to_string_with_sign(SignStyle::Parentheses),
+            "(1.2 MB)"
+        );
+        assert_eq!(
+            pretty_bytes_signed(-1_200_000, None).to_string_with_sign(SignStyle::Minus),
+            "-1.2 MB"
+        );
+        assert_eq!(
+            pretty_bytes_signed(1_200_000, None).to_string_with_sign(SignStyle::Parentheses),
+            "1.2 MB"
+        );
+        assert_eq!(
+            pretty_bytes_signed(0, None).to_string_with_sign(SignStyle::Parentheses),
+            "0 B"
         );
     }
 }
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+/// Struct that represents a prettified duration, such as the time taken by a transfer
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyDuration {
+    days: u64,
+    hours: u8,
+    minutes: u8,
+    seconds: u8,
+    millis: u16,
+}
+
+impl std::fmt::Display for PrettyDuration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Milliseconds are only shown for durations under a second
+        if self.days == 0 && self.hours == 0 && self.minutes == 0 && self.seconds == 0 {
+            return write!(f, "{} ms", self.millis);
+        }
+
+        let parts = [
+            (self.days, "d"),
+            (self.hours.into(), "h"),
+            (self.minutes.into(), "m"),
+            (self.seconds.into(), "s"),
+        ];
+
+        let mut first = true;
+        for (value, unit) in parts.into_iter().filter(|(value, _)| *value != 0) {
+            if !first {
+                f.write_str(" ")?;
+            }
+            first = false;
+            write!(f, "{value} {unit}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert a duration to a "prettified" version
+///
+/// Splits the duration into days, hours, minutes, and seconds, skipping any parts that are zero
+///
+/// ## Example
+/// ```
+/// # use std::time::Duration;
+/// # use pretty_bytes_typed::pretty_duration;
+/// let prettified = pretty_duration(Duration::from_secs(192));
+/// assert_eq!(prettified.to_string(), "3 m 12 s");
+///
+/// // Durations under a second are shown in milliseconds
+/// let prettified = pretty_duration(Duration::from_millis(250));
+/// assert_eq!(prettified.to_string(), "250 ms");
+/// ```
+pub const fn pretty_duration(duration: Duration) -> PrettyDuration {
+    let secs = duration.as_secs();
+
+    PrettyDuration {
+        days: secs / 86_400,
+        hours: (secs / 3600 % 24) as u8,
+        minutes: (secs / 60 % 60) as u8,
+        seconds: (secs % 60) as u8,
+        millis: duration.subsec_millis() as u16,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_duration() {
+        assert_eq!(pretty_duration(Duration::ZERO).to_string(), "0 ms");
+
+        assert_eq!(
+            pretty_duration(Duration::from_millis(999)).to_string(),
+            "999 ms"
+        );
+
+        assert_eq!(
+            pretty_duration(Duration::from_millis(1500)).to_string(),
+            "1 s"
+        );
+
+        assert_eq!(
+            pretty_duration(Duration::from_secs(3723)).to_string(),
+            "1 h 2 m 3 s"
+        );
+
+        assert_eq!(
+            pretty_duration(Duration::from_secs(86_405)).to_string(),
+            "1 d 5 s"
+        );
+
+        assert_eq!(
+            pretty_duration(Duration::from_secs(192)),
+            PrettyDuration {
+                days: 0,
+                hours: 0,
+                minutes: 3,
+                seconds: 12,
+                millis: 0,
+            }
+        );
+    }
+}
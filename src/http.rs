@@ -0,0 +1,255 @@
+use crate::{pretty_bytes, ByteQuota};
+
+/// Error returned when a `Content-Range` header can't be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseContentRangeError {
+    /// The header wasn't in the form `bytes <start>-<end>/<length>`
+    InvalidFormat,
+    /// The range unit wasn't `bytes`
+    UnknownUnit,
+    /// The range ends before it starts, or goes past the complete length
+    InvalidRange,
+}
+
+impl std::fmt::Display for ParseContentRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::InvalidFormat => "invalid Content-Range format",
+            Self::UnknownUnit => "unknown range unit",
+            Self::InvalidRange => "invalid range",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ParseContentRangeError {}
+
+/// Struct that represents a parsed `Content-Range` header
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContentRange {
+    range: Option<(u64, u64)>,
+    complete_length: Option<u64>,
+}
+
+impl ContentRange {
+    /// Get the first and last byte positions (inclusive), or `None` for an unsatisfied range (`bytes */<length>`)
+    #[must_use]
+    pub const fn range(&self) -> Option<(u64, u64)> {
+        self.range
+    }
+
+    /// Get the length of the whole resource, or `None` if it's unknown (`bytes <start>-<end>/*`)
+    #[must_use]
+    pub const fn complete_length(&self) -> Option<u64> {
+        self.complete_length
+    }
+
+    /// Get the number of bytes in the range
+    ///
+    /// Saturates at `u64::MAX` for the range `0-18446744073709551615`, which is one byte too long to count
+    #[must_use]
+    pub const fn len(&self) -> u64 {
+        match self.range {
+            Some((start, end)) => (end - start).saturating_add(1),
+            None => 0,
+        }
+    }
+
+    /// Check whether the range contains no bytes
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.range.is_none()
+    }
+
+    /// Get the progress through the whole resource once this range has been received
+    ///
+    /// ## Example
+    /// ```
+    /// # use pretty_bytes_typed::parse_content_range;
+    /// let content_range = parse_content_range("bytes 0-36628/146515").unwrap();
+    /// assert_eq!(content_range.progress().to_string(), "36.6 KB of 146.5 KB (25%)");
+    /// ```
+    pub const fn progress(&self) -> PrettyProgress {
+        let received = match self.range {
+            Some((_, end)) => end.saturating_add(1),
+            None => 0,
+        };
+
+        pretty_http_progress(received, self.complete_length)
+    }
+}
+
+/// Parse a `Content-Range` header value, such as `bytes 0-1023/146515`
+///
+/// ## Errors
+/// Returns an error if the header isn't a valid byte range
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::parse_content_range;
+/// let content_range = parse_content_range("bytes 0-1023/146515").unwrap();
+/// assert_eq!(content_range.range(), Some((0, 1023)));
+/// assert_eq!(content_range.len(), 1024);
+/// assert_eq!(content_range.complete_length(), Some(146_515));
+/// ```
+pub fn parse_content_range(header: &str) -> Result<ContentRange, ParseContentRangeError> {
+    let (unit, rest) = header
+        .trim()
+        .split_once(' ')
+        .ok_or(ParseContentRangeError::InvalidFormat)?;
+
+    // Range units are case-insensitive
+    if !unit.eq_ignore_ascii_case("bytes") {
+        return Err(ParseContentRangeError::UnknownUnit);
+    }
+
+    let (range, complete_length) = rest
+        .trim_start()
+        .split_once('/')
+        .ok_or(ParseContentRangeError::InvalidFormat)?;
+
+    let parse = |s: &str| {
+        s.parse::<u64>()
+            .map_err(|_| ParseContentRangeError::InvalidFormat)
+    };
+
+    let complete_length = match complete_length {
+        "*" => None,
+        complete_length => Some(parse(complete_length)?),
+    };
+
+    let range = match range {
+        "*" if complete_length.is_some() => None,
+        range => {
+            let (start, end) = range
+                .split_once('-')
+                .ok_or(ParseContentRangeError::InvalidFormat)?;
+            let (start, end) = (parse(start)?, parse(end)?);
+
+            if end < start || complete_length.is_some_and(|length| end >= length) {
+                return Err(ParseContentRangeError::InvalidRange);
+            }
+
+            Some((start, end))
+        }
+    };
+
+    Ok(ContentRange {
+        range,
+        complete_length,
+    })
+}
+
+/// Struct that represents the progress of a download
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyProgress {
+    received: u64,
+    total: Option<u64>,
+}
+
+impl std::fmt::Display for PrettyProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.total {
+            // Formatted the same way as a quota, with the total as the limit
+            Some(total) => ByteQuota::new(self.received, total).fmt(f),
+            None => pretty_bytes(self.received, Some(1)).fmt(f),
+        }
+    }
+}
+
+/// Summarize the progress of a download, given the number of bytes received so far and the `Content-Length` (if known)
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_http_progress;
+/// let progress = pretty_http_progress(1_200_000, Some(5_000_000));
+/// assert_eq!(progress.to_string(), "1.2 MB of 5 MB (24%)");
+///
+/// // Without a Content-Length, only the received bytes are shown
+/// let progress = pretty_http_progress(1_200_000, None);
+/// assert_eq!(progress.to_string(), "1.2 MB");
+/// ```
+pub const fn pretty_http_progress(received: u64, content_length: Option<u64>) -> PrettyProgress {
+    PrettyProgress {
+        received,
+        total: content_length,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_content_range() {
+        let content_range = parse_content_range("bytes 0-1023/146515").unwrap();
+        assert_eq!(content_range.range(), Some((0, 1023)));
+        assert_eq!(content_range.len(), 1024);
+        assert_eq!(content_range.complete_length(), Some(146_515));
+
+        let content_range = parse_content_range("bytes 146514-146514/146515").unwrap();
+        assert_eq!(content_range.len(), 1);
+        assert_eq!(
+            content_range.progress().to_string(),
+            "146.5 KB of 146.5 KB (100%)"
+        );
+
+        let content_range = parse_content_range("bytes 0-1023/*").unwrap();
+        assert_eq!(content_range.complete_length(), None);
+        assert_eq!(content_range.progress().to_string(), "1 KB");
+
+        let content_range = parse_content_range("bytes */146515").unwrap();
+        assert_eq!(content_range.range(), None);
+        assert!(content_range.is_empty());
+
+        let content_range = parse_content_range("Bytes 0-1023/146515").unwrap();
+        assert_eq!(content_range.range(), Some((0, 1023)));
+
+        let content_range = parse_content_range("bytes 0-18446744073709551615/*").unwrap();
+        assert_eq!(content_range.len(), u64::MAX);
+        assert_eq!(content_range.progress().to_string(), "18.4 EB");
+
+        assert_eq!(
+            parse_content_range("items 0-1/2"),
+            Err(ParseContentRangeError::UnknownUnit)
+        );
+        assert_eq!(
+            parse_content_range("bytes 0-1023"),
+            Err(ParseContentRangeError::InvalidFormat)
+        );
+        assert_eq!(
+            parse_content_range("bytes */*"),
+            Err(ParseContentRangeError::InvalidFormat)
+        );
+        assert_eq!(
+            parse_content_range("bytes a-1/2"),
+            Err(ParseContentRangeError::InvalidFormat)
+        );
+        assert_eq!(
+            parse_content_range("bytes 10-5/20"),
+            Err(ParseContentRangeError::InvalidRange)
+        );
+        assert_eq!(
+            parse_content_range("bytes 0-20/20"),
+            Err(ParseContentRangeError::InvalidRange)
+        );
+    }
+
+    #[test]
+    fn test_pretty_http_progress() {
+        assert_eq!(
+            pretty_http_progress(1_200_000, Some(5_000_000)).to_string(),
+            "1.2 MB of 5 MB (24%)"
+        );
+        assert_eq!(
+            pretty_http_progress(0, Some(0)).to_string(),
+            "0 B of 0 B (100%)"
+        );
+        assert_eq!(pretty_http_progress(512, None).to_string(), "512 B");
+    }
+}
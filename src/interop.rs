@@ -0,0 +1,162 @@
+// Conversions into these types use the exact number of bytes the prettified value was created from
+
+/// Error returned when converting a negative prettified value into a type that can only hold positive byte values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeBytesError;
+
+impl std::fmt::Display for NegativeBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("value is negative")
+    }
+}
+
+impl std::error::Error for NegativeBytesError {}
+
+#[cfg(feature = "bytesize")]
+mod bytesize_impls {
+    use bytesize::ByteSize;
+
+    use super::NegativeBytesError;
+    use crate::{pretty_bytes, pretty_bytes_binary, PrettyBytes, PrettyBytesBinary};
+
+    impl From<ByteSize> for PrettyBytes {
+        fn from(size: ByteSize) -> Self {
+            pretty_bytes(size.as_u64(), None)
+        }
+    }
+
+    impl From<ByteSize> for PrettyBytesBinary {
+        fn from(size: ByteSize) -> Self {
+            pretty_bytes_binary(size.as_u64(), None)
+        }
+    }
+
+    impl TryFrom<PrettyBytes> for ByteSize {
+        type Error = NegativeBytesError;
+
+        fn try_from(pretty_bytes: PrettyBytes) -> Result<Self, Self::Error> {
+            u64::try_from(pretty_bytes.byte_value())
+                .map(Self::b)
+                .map_err(|_| NegativeBytesError)
+        }
+    }
+
+    impl TryFrom<PrettyBytesBinary> for ByteSize {
+        type Error = NegativeBytesError;
+
+        fn try_from(pretty_bytes: PrettyBytesBinary) -> Result<Self, Self::Error> {
+            u64::try_from(pretty_bytes.byte_value())
+                .map(Self::b)
+                .map_err(|_| NegativeBytesError)
+        }
+    }
+}
+
+#[cfg(feature = "byte-unit")]
+mod byte_unit_impls {
+    use byte_unit::Byte;
+
+    use super::NegativeBytesError;
+    use crate::{pretty_bytes, pretty_bytes_binary, PrettyBytes, PrettyBytesBinary};
+
+    // `Byte` can hold values larger than a u64, which are saturated to `u64::MAX`
+    impl From<Byte> for PrettyBytes {
+        fn from(byte: Byte) -> Self {
+            pretty_bytes(byte.as_u64(), None)
+        }
+    }
+
+    impl From<Byte> for PrettyBytesBinary {
+        fn from(byte: Byte) -> Self {
+            pretty_bytes_binary(byte.as_u64(), None)
+        }
+    }
+
+    impl TryFrom<PrettyBytes> for Byte {
+        type Error = NegativeBytesError;
+
+        fn try_from(pretty_bytes: PrettyBytes) -> Result<Self, Self::Error> {
+            u64::try_from(pretty_bytes.byte_value())
+                .map(Self::from_u64)
+                .map_err(|_| NegativeBytesError)
+        }
+    }
+
+    impl TryFrom<PrettyBytesBinary> for Byte {
+        type Error = NegativeBytesError;
+
+        fn try_from(pretty_bytes: PrettyBytesBinary) -> Result<Self, Self::Error> {
+            u64::try_from(pretty_bytes.byte_value())
+                .map(Self::from_u64)
+                .map_err(|_| NegativeBytesError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NegativeBytesError;
+    use crate::{pretty_bytes, pretty_bytes_binary, PrettyBytes, PrettyBytesBinary};
+
+    #[test]
+    #[cfg(feature = "bytesize")]
+    fn test_bytesize() {
+        use bytesize::ByteSize;
+
+        use crate::pretty_bytes_signed;
+
+        assert_eq!(
+            PrettyBytes::from(ByteSize::mb(2)),
+            pretty_bytes(2_000_000, None)
+        );
+        assert_eq!(
+            PrettyBytesBinary::from(ByteSize::mib(2)),
+            pretty_bytes_binary(2_097_152, None)
+        );
+
+        assert_eq!(
+            ByteSize::try_from(pretty_bytes(3_564_234, None)),
+            Ok(ByteSize::b(3_564_234))
+        );
+        assert_eq!(
+            ByteSize::try_from(pretty_bytes(3_564_234, Some(2))),
+            Ok(ByteSize::b(3_564_234))
+        );
+        assert_eq!(
+            ByteSize::try_from(pretty_bytes_binary(2_621_440, None)),
+            Ok(ByteSize::b(2_621_440))
+        );
+        assert_eq!(
+            ByteSize::try_from(pretty_bytes_signed(-1_000, None)),
+            Err(NegativeBytesError)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "byte-unit")]
+    fn test_byte_unit() {
+        use byte_unit::Byte;
+
+        assert_eq!(
+            PrettyBytes::from(Byte::from_u64(2_000_000)),
+            pretty_bytes(2_000_000, None)
+        );
+        assert_eq!(
+            PrettyBytesBinary::from(Byte::from_u64(2_097_152)),
+            pretty_bytes_binary(2_097_152, None)
+        );
+
+        assert_eq!(
+            Byte::try_from(pretty_bytes(3_564_234, None)),
+            Ok(Byte::from_u64(3_564_234))
+        );
+        assert_eq!(
+            Byte::try_from(pretty_bytes_binary(2_621_441, Some(1))),
+            Ok(Byte::from_u64(2_621_441))
+        );
+        assert_eq!(
+            Byte::try_from(crate::pretty_bytes_signed(-1, None)),
+            Err(NegativeBytesError)
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use std::{
+    io::{Read, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    pretty_bytes, pretty_rate, pretty_transfer_summary, PrettyBytes, PrettyRate,
+    PrettyTransferSummary,
+};
+
+struct Callback {
+    interval: Duration,
+    last_called: Instant,
+    function: Box<dyn FnMut(u64, Duration) + Send>,
+}
+
+// Shared bookkeeping for `CountingReader` and `CountingWriter`
+struct Counter {
+    bytes: u64,
+    start: Instant,
+    callback: Option<Callback>,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Self {
+            bytes: 0,
+            start: Instant::now(),
+            callback: None,
+        }
+    }
+
+    fn record(&mut self, bytes: usize) {
+        self.bytes = self.bytes.saturating_add(bytes as u64);
+
+        if let Some(callback) = &mut self.callback {
+            if callback.last_called.elapsed() >= callback.interval {
+                callback.last_called = Instant::now();
+                (callback.function)(self.bytes, self.start.elapsed());
+            }
+        }
+    }
+
+    fn set_callback(
+        &mut self,
+        interval: Duration,
+        callback: impl FnMut(u64, Duration) + Send + 'static,
+    ) {
+        self.callback = Some(Callback {
+            interval,
+            last_called: Instant::now(),
+            function: Box::new(callback),
+        });
+    }
+}
+
+macro_rules! counting_methods {
+    ($inner:ident) => {
+        /// Call `callback` with the number of bytes transferred and the time elapsed so far, at most once every `interval`
+        ///
+        /// The callback is only checked when data is transferred, so it won't be called while the transfer is stalled
+        #[must_use]
+        pub fn with_callback(
+            mut self,
+            interval: Duration,
+            callback: impl FnMut(u64, Duration) + Send + 'static,
+        ) -> Self {
+            self.counter.set_callback(interval, callback);
+            self
+        }
+
+        /// Get the number of bytes transferred so far
+        #[must_use]
+        pub const fn bytes_transferred(&self) -> u64 {
+            self.counter.bytes
+        }
+
+        /// Get the time elapsed since the wrapper was created
+        #[must_use]
+        pub fn elapsed(&self) -> Duration {
+            self.counter.start.elapsed()
+        }
+
+        /// Get the number of bytes transferred so far as a "prettified" value (base-10)
+        pub fn pretty_total(&self, round_places: Option<u8>) -> PrettyBytes {
+            pretty_bytes(self.bytes_transferred(), round_places)
+        }
+
+        /// Get the average transfer rate so far as a "prettified" value (base-10)
+        pub fn pretty_rate(&self, round_places: Option<u8>) -> PrettyRate {
+            pretty_rate(self.bytes_transferred(), self.elapsed(), round_places)
+        }
+
+        /// Summarize the transfer so far
+        pub fn summary(&self, round_places: Option<u8>) -> PrettyTransferSummary {
+            pretty_transfer_summary(self.bytes_transferred(), self.elapsed(), round_places)
+        }
+
+        /// Get a reference to the wrapped value
+        pub const fn get_ref(&self) -> &$inner {
+            &self.inner
+        }
+
+        /// Get a mutable reference to the wrapped value
+        ///
+        /// Data transferred directly through this reference isn't counted
+        pub const fn get_mut(&mut self) -> &mut $inner {
+            &mut self.inner
+        }
+
+        /// Unwrap the wrapped value
+        pub fn into_inner(self) -> $inner {
+            self.inner
+        }
+    };
+}
+
+/// Reader that counts the bytes read through it and the time taken
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::CountingReader;
+/// let mut reader = CountingReader::new(&[0_u8; 2048][..]);
+/// std::io::copy(&mut reader, &mut std::io::sink()).unwrap();
+///
+/// assert_eq!(reader.bytes_transferred(), 2048);
+/// assert_eq!(reader.pretty_total(None).to_string(), "2.048 KB");
+/// ```
+pub struct CountingReader<R> {
+    inner: R,
+    counter: Counter,
+}
+
+// Leaves out the callback, which can't be shown
+impl<R: std::fmt::Debug> std::fmt::Debug for CountingReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountingReader")
+            .field("inner", &self.inner)
+            .field("bytes", &self.counter.bytes)
+            .field("start", &self.counter.start)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R> CountingReader<R> {
+    /// Wrap a reader, starting the clock now
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            counter: Counter::new(),
+        }
+    }
+
+    counting_methods!(R);
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.counter.record(read);
+        Ok(read)
+    }
+}
+
+/// Writer that counts the bytes written through it and the time taken
+///
+/// ## Example
+/// ```
+/// # use std::io::Write;
+/// # use pretty_bytes_typed::CountingWriter;
+/// let mut writer = CountingWriter::new(Vec::new());
+/// writer.write_all(&[0; 1_500_000]).unwrap();
+///
+/// assert_eq!(writer.bytes_transferred(), 1_500_000);
+/// assert_eq!(writer.pretty_total(None).to_string(), "1.5 MB");
+/// ```
+pub struct CountingWriter<W> {
+    inner: W,
+    counter: Counter,
+}
+
+// Leaves out the callback, which can't be shown
+impl<W: std::fmt::Debug> std::fmt::Debug for CountingWriter<W> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CountingWriter")
+            .field("inner", &self.inner)
+            .field("bytes", &self.counter.bytes)
+            .field("start", &self.counter.start)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W> CountingWriter<W> {
+    /// Wrap a writer, starting the clock now
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            counter: Counter::new(),
+        }
+    }
+
+    counting_methods!(W);
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.counter.record(written);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[test]
+    fn test_counting_reader() {
+        let mut reader = CountingReader::new(&[1_u8; 5000][..]);
+
+        let mut buf = [0; 2000];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2000);
+        assert_eq!(reader.bytes_transferred(), 2000);
+
+        std::io::copy(&mut reader, &mut std::io::sink()).unwrap();
+        assert_eq!(reader.bytes_transferred(), 5000);
+        assert_eq!(reader.pretty_total(None).to_string(), "5 KB");
+        assert!(reader.get_ref().is_empty());
+        assert!(reader.summary(Some(1)).to_string().starts_with("5 KB in "));
+        assert!(
+            format!("{reader:?}").starts_with("CountingReader { inner: [], bytes: 5000, start: ")
+        );
+    }
+
+    #[test]
+    fn test_counting_writer() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let calls_ref = Arc::clone(&calls);
+
+        let mut writer =
+            CountingWriter::new(Vec::new()).with_callback(Duration::ZERO, move |bytes, _| {
+                calls_ref.lock().unwrap().push(bytes);
+            });
+
+        writer.write_all(&[0; 1000]).unwrap();
+        writer.write_all(&[0; 500]).unwrap();
+        writer.flush().unwrap();
+
+        assert_eq!(writer.bytes_transferred(), 1500);
+        assert_eq!(writer.pretty_total(None).to_string(), "1.5 KB");
+        assert_eq!(*calls.lock().unwrap(), [1000, 1500]);
+        assert!(format!("{writer:?}").ends_with(", .. }"));
+        assert_eq!(writer.into_inner().len(), 1500);
+    }
+}
@@ -10,10 +10,66 @@
 //! A simple, no-dependencies crate for converting a number of bytes into a strongly-typed (stack-allocated) representation of the "prettified" version of those bytes.
 //!
 //! Compatible with `serde` when the `serde` feature is enabled.
+//!
+//! Can be converted to and from `bytesize::ByteSize` and `byte_unit::Byte` when the `bytesize` and `byte-unit` features are enabled. Converting a negative value into one of those types fails with `NegativeBytesError`.
+//!
+//! `pretty_bytes_f32` and `pretty_bytes_binary_f32` perform the mantissa math in `f32` instead of `f64`, which is much cheaper on targets without hardware double-precision support.
+//!
+//! `#[derive(PrettyBytesFields)]` implements `Display` for structs with byte count fields when the `derive` feature is enabled, and `#[derive(PrettyBytesSerialize)]` implements `serde::Serialize` for them when the `serde` feature is also enabled.
+//!
+//! `CountingReader` and `CountingWriter` wrap `std::io` types to track and prettify the bytes transferred through them when the `io` feature is enabled.
+//!
+//! Helpers for HTTP `Content-Length` and `Content-Range` headers are available when the `http` feature is enabled.
+//!
+//! Unit names (both abbreviated and long) and decimal separators can be translated into a few common languages when the `translations` feature is enabled.
 
 mod binary;
+mod change;
 mod decimal;
+mod duration;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(any(feature = "bytesize", feature = "byte-unit"))]
+mod interop;
+#[cfg(feature = "io")]
+mod io;
+mod maybe;
+mod parse;
+mod quota;
+mod rate;
+mod sign;
+mod single_precision;
+#[cfg(feature = "translations")]
+mod translations;
 mod util;
 
 pub use binary::*;
+pub use change::*;
 pub use decimal::*;
+pub use duration::*;
+#[cfg(feature = "http")]
+pub use http::*;
+#[cfg(any(feature = "bytesize", feature = "byte-unit"))]
+pub use interop::*;
+#[cfg(feature = "io")]
+pub use io::*;
+pub use maybe::*;
+pub use parse::*;
+pub use quota::*;
+pub use rate::*;
+pub use sign::*;
+pub use single_precision::*;
+#[cfg(feature = "translations")]
+pub use translations::*;
+
+#[cfg(feature = "derive")]
+pub use pretty_bytes_typed_derive::PrettyBytesFields;
+#[cfg(all(feature = "derive", feature = "serde"))]
+pub use pretty_bytes_typed_derive::PrettyBytesSerialize;
+
+// Used by the code generated by `#[derive(PrettyBytesSerialize)]`, so that users don't need to depend on `serde` themselves
+#[cfg(all(feature = "derive", feature = "serde"))]
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
@@ -0,0 +1,133 @@
+use crate::{pretty_bytes, pretty_bytes_binary, PrettyBytes, PrettyBytesBinary};
+
+/// Struct that displays a prettified byte value if there is one, and a placeholder otherwise
+///
+/// Respects width, fill, and alignment when formatting, so it can be used directly in tables. Precision is ignored, rather than cutting off the unit.
+#[derive(Debug, PartialEq, Clone)]
+// `Eq` could never apply, since the prettified values it holds contain floats
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[must_use]
+pub struct MaybeBytes<'a, T> {
+    value: Option<T>,
+    placeholder: &'a str,
+}
+
+impl<T: std::fmt::Display> std::fmt::Display for MaybeBytes<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.value {
+            Some(value) => pad(f, &value.to_string()),
+            None => pad(f, self.placeholder),
+        }
+    }
+}
+
+// Like `Formatter::pad`, but without treating the precision as a maximum length
+fn pad(f: &mut std::fmt::Formatter<'_>, s: &str) -> std::fmt::Result {
+    let padding = f.width().unwrap_or(0).saturating_sub(s.chars().count());
+
+    let (before, after) = match f.align() {
+        Some(std::fmt::Alignment::Right) => (padding, 0),
+        Some(std::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(std::fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        std::fmt::Write::write_char(f, fill)?;
+    }
+    f.write_str(s)?;
+    for _ in 0..after {
+        std::fmt::Write::write_char(f, fill)?;
+    }
+
+    Ok(())
+}
+
+/// Convert a byte value that may be missing to a "prettified" version, using a placeholder if it's missing
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB)
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_maybe;
+/// let prettified = pretty_bytes_maybe(Some(2_000_000), None, "—");
+/// assert_eq!(prettified.to_string(), "2 MB");
+///
+/// let prettified = pretty_bytes_maybe(None, None, "unknown");
+/// assert_eq!(format!("[{prettified:>8}]"), "[ unknown]");
+/// ```
+pub fn pretty_bytes_maybe(
+    num: Option<u64>,
+    round_places: Option<u8>,
+    placeholder: &str,
+) -> MaybeBytes<'_, PrettyBytes> {
+    MaybeBytes {
+        value: num.map(|num| pretty_bytes(num, round_places)),
+        placeholder,
+    }
+}
+
+/// Convert a byte value that may be missing to a "prettified" version, using a placeholder if it's missing
+///
+/// Converts using base-2 byte suffixes (KiB, MiB, GiB)
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_maybe_binary;
+/// let prettified = pretty_bytes_maybe_binary(Some(1_048_576), None, "—");
+/// assert_eq!(prettified.to_string(), "1 MiB");
+///
+/// let prettified = pretty_bytes_maybe_binary(None, None, "—");
+/// assert_eq!(prettified.to_string(), "—");
+/// ```
+pub fn pretty_bytes_maybe_binary(
+    num: Option<u64>,
+    round_places: Option<u8>,
+    placeholder: &str,
+) -> MaybeBytes<'_, PrettyBytesBinary> {
+    MaybeBytes {
+        value: num.map(|num| pretty_bytes_binary(num, round_places)),
+        placeholder,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_bytes_maybe() {
+        assert_eq!(
+            pretty_bytes_maybe(Some(3_564_234), Some(2), "—").to_string(),
+            "3.56 MB"
+        );
+        assert_eq!(pretty_bytes_maybe(None, Some(2), "—").to_string(), "—");
+        assert_eq!(
+            format!(
+                "{:>10}|{:<10}|",
+                pretty_bytes_maybe(Some(5_430), None, "-"),
+                pretty_bytes_maybe(None, None, "-")
+            ),
+            "   5.43 KB|-         |"
+        );
+
+        assert_eq!(
+            pretty_bytes_maybe_binary(Some(2_621_440), None, "unknown").to_string(),
+            "2.5 MiB"
+        );
+        assert_eq!(
+            format!("{:^9}", pretty_bytes_maybe_binary(None, None, "unknown")),
+            " unknown "
+        );
+
+        // Precision doesn't cut off the unit
+        assert_eq!(
+            format!("{:.3}", pretty_bytes_maybe(Some(1500), None, "-")),
+            "1.5 KB"
+        );
+        assert_eq!(
+            format!("{:*>8.2}", pretty_bytes_maybe(Some(1500), None, "-")),
+            "**1.5 KB"
+        );
+    }
+}
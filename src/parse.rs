@@ -0,0 +1,588 @@
+use crate::{ByteValues, ByteValuesBinary, PrettyBytes, PrettyBytesBinary};
+
+/// Error returned when a string can't be parsed as a byte value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBytesError {
+    /// The string didn't start with a valid number
+    InvalidNumber,
+    /// The unit after the number wasn't recognized
+    UnknownUnit,
+    /// The value is too large to fit in a `u64`
+    OutOfRange,
+}
+
+impl std::fmt::Display for ParseBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            Self::InvalidNumber => "invalid number",
+            Self::UnknownUnit => "unknown unit",
+            Self::OutOfRange => "value is too large",
+        };
+
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for ParseBytesError {}
+
+/// Error for a single value in a column that couldn't be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnParseError {
+    index: usize,
+    error: ParseBytesError,
+}
+
+impl ColumnParseError {
+    /// Get the (zero-based) index of the value in the column
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Get the reason the value couldn't be parsed
+    #[must_use]
+    pub const fn error(&self) -> ParseBytesError {
+        self.error
+    }
+}
+
+impl std::fmt::Display for ColumnParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "value {}: {}", self.index, self.error)
+    }
+}
+
+impl std::error::Error for ColumnParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Parses a "prettified" byte value, keeping the number and unit as they were written instead of renormalizing them
+///
+/// Only accepts base-10 suffixes (KB, MB, GB), ignoring case. To renormalize, use [`parse_bytes`] and [`pretty_bytes`](crate::pretty_bytes) instead.
+///
+/// Fractional values in bytes (like "1.5 B") are rejected. For larger units, the number of bytes used for comparisons is rounded to the nearest byte.
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::PrettyBytes;
+/// let prettified: PrettyBytes = "2048 KB".parse().unwrap();
+/// assert_eq!(prettified.to_string(), "2048 KB");
+///
+/// let prettified: PrettyBytes = "-1.5 MB".parse().unwrap();
+/// assert_eq!(prettified.to_string(), "-1.5 MB");
+/// ```
+impl std::str::FromStr for PrettyBytes {
+    type Err = ParseBytesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (num, unit, bytes) = parse_preserving_unit(s, &DECIMAL_UNITS)?;
+
+        Ok(Self::from_parts(num, ByteValues::UNITS[unit], bytes))
+    }
+}
+
+/// Parses a "prettified" byte value, keeping the number and unit as they were written instead of renormalizing them
+///
+/// Only accepts base-2 suffixes (KiB, MiB, GiB), ignoring case. To renormalize, use [`parse_bytes`] and [`pretty_bytes_binary`](crate::pretty_bytes_binary) instead.
+///
+/// Fractional values in bytes (like "1.5 B") are rejected. For larger units, the number of bytes used for comparisons is rounded to the nearest byte.
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::PrettyBytesBinary;
+/// let prettified: PrettyBytesBinary = "2048 KiB".parse().unwrap();
+/// assert_eq!(prettified.to_string(), "2048 KiB");
+/// ```
+impl std::str::FromStr for PrettyBytesBinary {
+    type Err = ParseBytesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (num, unit, bytes) = parse_preserving_unit(s, &BINARY_UNITS)?;
+
+        Ok(Self::from_parts(num, ByteValuesBinary::UNITS[unit], bytes))
+    }
+}
+
+// Only as many fractional digits as are needed to tell apart single bytes at EiB scale are considered
+const MAX_FRACTION_DIGITS: usize = 19;
+
+// Ordered the same as `ByteValues::UNITS`
+const DECIMAL_UNITS: [(&str, u64); 7] = [
+    ("B", ByteValues::B.bytes()),
+    ("KB", ByteValues::KB.bytes()),
+    ("MB", ByteValues::MB.bytes()),
+    ("GB", ByteValues::GB.bytes()),
+    ("TB", ByteValues::TB.bytes()),
+    ("PB", ByteValues::PB.bytes()),
+    ("EB", ByteValues::EB.bytes()),
+];
+
+// Ordered the same as `ByteValuesBinary::UNITS`
+const BINARY_UNITS: [(&str, u64); 7] = [
+    ("B", ByteValuesBinary::B.bytes()),
+    ("KiB", ByteValuesBinary::KiB.bytes()),
+    ("MiB", ByteValuesBinary::MiB.bytes()),
+    ("GiB", ByteValuesBinary::GiB.bytes()),
+    ("TiB", ByteValuesBinary::TiB.bytes()),
+    ("PiB", ByteValuesBinary::PiB.bytes()),
+    ("EiB", ByteValuesBinary::EiB.bytes()),
+];
+
+// procfs and sysfs always mean base-2 units, even when they're written like base-10 ones
+const PROCFS_UNITS: [(&str, u64); 13] = [
+    ("B", ByteValuesBinary::B.bytes()),
+    ("K", ByteValuesBinary::KiB.bytes()),
+    ("KB", ByteValuesBinary::KiB.bytes()),
+    ("M", ByteValuesBinary::MiB.bytes()),
+    ("MB", ByteValuesBinary::MiB.bytes()),
+    ("G", ByteValuesBinary::GiB.bytes()),
+    ("GB", ByteValuesBinary::GiB.bytes()),
+    ("T", ByteValuesBinary::TiB.bytes()),
+    ("TB", ByteValuesBinary::TiB.bytes()),
+    ("P", ByteValuesBinary::PiB.bytes()),
+    ("PB", ByteValuesBinary::PiB.bytes()),
+    ("E", ByteValuesBinary::EiB.bytes()),
+    ("EB", ByteValuesBinary::EiB.bytes()),
+];
+
+/// Parse a "prettified" byte value back into a number of bytes
+///
+/// Accepts both base-10 (KB, MB, GB) and base-2 (KiB, MiB, GiB) suffixes, ignoring case. A value without a suffix is treated as bytes.
+/// Fractional values are rounded to the nearest byte.
+///
+/// Numbers can also be written in hexadecimal (`0x4000`) or binary (`0b1010`), and can contain underscores (`1_048_576`).
+/// Since "B" is a hexadecimal digit, put a space between a hexadecimal number and its unit.
+///
+/// ## Errors
+/// Returns an error if the number or unit is invalid, or if the value doesn't fit in a `u64`
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::parse_bytes;
+/// assert_eq!(parse_bytes("3.564234 MB"), Ok(3_564_234));
+/// assert_eq!(parse_bytes("2.5KiB"), Ok(2560));
+/// assert_eq!(parse_bytes("512"), Ok(512));
+/// assert_eq!(parse_bytes("0x10 KiB"), Ok(16_384));
+/// ```
+pub fn parse_bytes(s: &str) -> Result<u64, ParseBytesError> {
+    match parse_with_units(s, &DECIMAL_UNITS) {
+        Err(ParseBytesError::UnknownUnit) => parse_with_units(s, &BINARY_UNITS),
+        result => result,
+    }
+}
+
+/// Parse a byte value in the format used by Linux's procfs and sysfs (such as `/proc/meminfo`) into a number of bytes
+///
+/// These files write "kB", "MB", and "GB" (or just "K", "M", and "G", like `du -h`, which also goes up to "T", "P", and "E"), but always mean base-2 units, so "1 kB" is parsed as 1024 bytes
+///
+/// ## Errors
+/// Returns an error if the number or unit is invalid, or if the value doesn't fit in a `u64`
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::parse_bytes_procfs;
+/// assert_eq!(parse_bytes_procfs("16384 kB"), Ok(16_777_216));
+/// ```
+pub fn parse_bytes_procfs(s: &str) -> Result<u64, ParseBytesError> {
+    parse_with_units(s, &PROCFS_UNITS)
+}
+
+/// Parse a whole column of "prettified" byte values, such as the sizes exported by `du -h` or a spreadsheet
+///
+/// Each value is parsed with `parse` (usually [`parse_bytes`], or [`parse_bytes_procfs`] for the base-2 "K"/"M"/"G" suffixes that `du -h` uses).
+/// Blank values are skipped, so each parsed value is returned along with its (zero-based) index in the column.
+///
+/// ## Errors
+/// Returns an error for every value that couldn't be parsed, rather than stopping at the first one
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::{parse_bytes, parse_bytes_column, parse_bytes_procfs};
+/// let column = "1.5 MB\n512 KB\n\n2 GB\n";
+/// assert_eq!(
+///     parse_bytes_column(column.lines(), parse_bytes),
+///     Ok(vec![(0, 1_500_000), (1, 512_000), (3, 2_000_000_000)])
+/// );
+///
+/// let du_output = "4.0K\t./src\n1.5M\t./target\n";
+/// let sizes = du_output.lines().filter_map(|line| line.split('\t').next());
+/// assert_eq!(
+///     parse_bytes_column(sizes, parse_bytes_procfs),
+///     Ok(vec![(0, 4096), (1, 1_572_864)])
+/// );
+/// ```
+pub fn parse_bytes_column<'a>(
+    values: impl IntoIterator<Item = &'a str>,
+    parse: impl Fn(&str) -> Result<u64, ParseBytesError>,
+) -> Result<Vec<(usize, u64)>, Vec<ColumnParseError>> {
+    let mut parsed = Vec::new();
+    let mut errors = Vec::new();
+
+    for (index, value) in values.into_iter().enumerate() {
+        if value.trim().is_empty() {
+            continue;
+        }
+
+        match parse(value) {
+            Ok(num) => parsed.push((index, num)),
+            Err(error) => errors.push(ColumnParseError { index, error }),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(parsed)
+    } else {
+        Err(errors)
+    }
+}
+
+fn parse_with_units(s: &str, units: &[(&str, u64)]) -> Result<u64, ParseBytesError> {
+    let (number, radix, unit) = split_parts(s, units)?;
+
+    to_bytes(&number, radix, units[unit].1)
+}
+
+// Parses the mantissa, keeping the unit as-is, and returns it along with the index of the unit in `units` and the exact number of bytes
+// Negative values are accepted, since that's how the signed constructors display them
+// Most likely, values will be too small to experience precision loss
+#[allow(clippy::cast_precision_loss)]
+fn parse_preserving_unit(
+    s: &str,
+    units: &[(&str, u64)],
+) -> Result<(f64, usize, i128), ParseBytesError> {
+    let s = s.trim();
+    let (is_negative, s) = s.strip_prefix('-').map_or((false, s), |s| (true, s));
+
+    // The sign has to be directly in front of the number
+    if s.starts_with(char::is_whitespace) {
+        return Err(ParseBytesError::InvalidNumber);
+    }
+
+    let (number, radix, unit) = split_parts(s, units)?;
+
+    // A fraction of a byte can't be stored, so it would display differently than it compares
+    let is_fractional = number
+        .split_once('.')
+        .is_some_and(|(_, frac)| frac.bytes().any(|b| b != b'0'));
+    if units[unit].1 == 1 && is_fractional {
+        return Err(ParseBytesError::InvalidNumber);
+    }
+
+    // Also makes sure that the value would fit in a u64
+    let bytes = to_bytes(&number, radix, units[unit].1)?;
+
+    let mantissa = if radix == 10 {
+        number.parse().map_err(|_| ParseBytesError::InvalidNumber)?
+    } else {
+        parse_integer(&number, radix, 1)? as f64
+    };
+
+    let bytes = i128::from(bytes);
+
+    if is_negative {
+        Ok((-mantissa, unit, -bytes))
+    } else {
+        Ok((mantissa, unit, bytes))
+    }
+}
+
+// Returns the number (with underscores removed), its radix, and the index of the unit in `units`
+fn split_parts(s: &str, units: &[(&str, u64)]) -> Result<(String, u32, usize), ParseBytesError> {
+    let (number, unit, radix) = split_number(s.trim());
+
+    if number.is_empty() || (radix == 10 && number.starts_with('_')) {
+        return Err(ParseBytesError::InvalidNumber);
+    }
+
+    let unit = parse_unit(unit.trim_start(), units)?;
+
+    Ok((number.replace('_', ""), radix, unit))
+}
+
+fn to_bytes(number: &str, radix: u32, multiplier: u64) -> Result<u64, ParseBytesError> {
+    if radix == 10 {
+        parse_number(number, multiplier)
+    } else {
+        parse_integer(number, radix, multiplier)
+    }
+}
+
+// Splits off the number (without any radix prefix), and returns it along with the rest of the string and the radix
+fn split_number(s: &str) -> (&str, &str, u32) {
+    for (prefix, radix) in [("0x", 16), ("0b", 2)] {
+        if let Some(rest) = s.strip_prefix(prefix) {
+            let split = rest
+                .find(|c: char| !(c.is_digit(radix) || c == '_'))
+                .unwrap_or(rest.len());
+
+            // Otherwise, something like "0b" is zero bytes
+            if split > 0 {
+                return (&rest[..split], &rest[split..], radix);
+            }
+        }
+    }
+
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '_'))
+        .unwrap_or(s.len());
+
+    (&s[..split], &s[split..], 10)
+}
+
+// Every unit table starts with bytes, which is also used when there's no unit
+fn parse_unit(unit: &str, units: &[(&str, u64)]) -> Result<usize, ParseBytesError> {
+    if unit.is_empty() {
+        return Ok(0);
+    }
+
+    units
+        .iter()
+        .position(|(name, _)| name.eq_ignore_ascii_case(unit))
+        .ok_or(ParseBytesError::UnknownUnit)
+}
+
+fn parse_integer(number: &str, radix: u32, multiplier: u64) -> Result<u64, ParseBytesError> {
+    let num = u64::from_str_radix(number, radix).map_err(|err| match err.kind() {
+        std::num::IntErrorKind::PosOverflow => ParseBytesError::OutOfRange,
+        _ => ParseBytesError::InvalidNumber,
+    })?;
+
+    num.checked_mul(multiplier)
+        .ok_or(ParseBytesError::OutOfRange)
+}
+
+// Uses integer math, so that large values are parsed exactly
+fn parse_number(number: &str, multiplier: u64) -> Result<u64, ParseBytesError> {
+    let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (int.is_empty() && frac.is_empty()) || !is_digits(int) || !is_digits(frac) {
+        return Err(ParseBytesError::InvalidNumber);
+    }
+
+    let multiplier = u128::from(multiplier);
+
+    let int = if int.is_empty() {
+        0
+    } else {
+        int.parse::<u128>()
+            .map_err(|_| ParseBytesError::OutOfRange)?
+    };
+
+    let frac = &frac[..frac.len().min(MAX_FRACTION_DIGITS)];
+    let frac_bytes = if frac.is_empty() {
+        0
+    } else {
+        let scale = 10_u128.pow(frac.len() as u32);
+        // Can't fail, since there are only digits and few enough of them
+        let frac = frac.parse::<u128>().unwrap_or_default();
+        (frac * multiplier + scale / 2) / scale
+    };
+
+    int.checked_mul(multiplier)
+        .and_then(|bytes| bytes.checked_add(frac_bytes))
+        .and_then(|bytes| u64::try_from(bytes).ok())
+        .ok_or(ParseBytesError::OutOfRange)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bytes() {
+        assert_eq!(parse_bytes("0"), Ok(0));
+        assert_eq!(parse_bytes("512 B"), Ok(512));
+        assert_eq!(parse_bytes("  2 MB "), Ok(2_000_000));
+        assert_eq!(parse_bytes("3.564234 MB"), Ok(3_564_234));
+        assert_eq!(parse_bytes("3.56 mb"), Ok(3_560_000));
+        assert_eq!(parse_bytes("1KiB"), Ok(1024));
+        assert_eq!(parse_bytes("1.5 kib"), Ok(1536));
+        assert_eq!(parse_bytes(".5 KB"), Ok(500));
+        assert_eq!(parse_bytes("1. KB"), Ok(1000));
+        assert_eq!(parse_bytes("1 EiB"), Ok(1024_u64.pow(6)));
+
+        // Rounded to the nearest byte
+        assert_eq!(parse_bytes("1.0005 KB"), Ok(1001));
+        assert_eq!(parse_bytes("0.4 B"), Ok(0));
+
+        // Extra large values
+        assert_eq!(parse_bytes("18.446744073709551615 EB"), Ok(u64::MAX));
+        assert_eq!(
+            parse_bytes("18.446744073709551616 EB"),
+            Err(ParseBytesError::OutOfRange)
+        );
+        assert_eq!(parse_bytes("16 EiB"), Err(ParseBytesError::OutOfRange));
+        assert_eq!(
+            parse_bytes("1000000000000000000000000000000000000000"),
+            Err(ParseBytesError::OutOfRange)
+        );
+
+        // Invalid values
+        assert_eq!(parse_bytes(""), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("MB"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes(". MB"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("1.2.3 MB"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("-1 MB"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("1 XB"), Err(ParseBytesError::UnknownUnit));
+        assert_eq!(parse_bytes("1 MB MB"), Err(ParseBytesError::UnknownUnit));
+    }
+
+    #[test]
+    fn test_parse_bytes_literals() {
+        assert_eq!(parse_bytes("0x4000"), Ok(16_384));
+        assert_eq!(parse_bytes("0xff_ff"), Ok(65_535));
+        assert_eq!(parse_bytes("0x10 KiB"), Ok(16_384));
+        assert_eq!(parse_bytes("0x10B"), Ok(267));
+        assert_eq!(parse_bytes("0b1010"), Ok(10));
+        assert_eq!(parse_bytes("0b1010 MB"), Ok(10_000_000));
+        assert_eq!(parse_bytes("0b1010B"), Ok(10));
+        assert_eq!(parse_bytes("1_048_576"), Ok(1_048_576));
+        assert_eq!(parse_bytes("1_000.5 KB"), Ok(1_000_500));
+
+        // Without any digits, the prefix is just a number and unit
+        assert_eq!(parse_bytes("0b"), Ok(0));
+        assert_eq!(parse_bytes("0x"), Err(ParseBytesError::UnknownUnit));
+
+        assert_eq!(parse_bytes("0xffff_ffff_ffff_ffff"), Ok(u64::MAX));
+        assert_eq!(
+            parse_bytes("0x1_0000_0000_0000_0000"),
+            Err(ParseBytesError::OutOfRange)
+        );
+        assert_eq!(parse_bytes("0x10 EiB"), Err(ParseBytesError::OutOfRange));
+        assert_eq!(parse_bytes("0x_"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("_1"), Err(ParseBytesError::InvalidNumber));
+        assert_eq!(parse_bytes("0x1.5"), Err(ParseBytesError::UnknownUnit));
+    }
+
+    #[test]
+    fn test_parse_bytes_procfs() {
+        assert_eq!(parse_bytes_procfs("16384 kB"), Ok(16_777_216));
+        assert_eq!(parse_bytes_procfs("0 kB"), Ok(0));
+        assert_eq!(parse_bytes_procfs("4096"), Ok(4096));
+        assert_eq!(parse_bytes_procfs("2048K"), Ok(2_097_152));
+        assert_eq!(parse_bytes_procfs("2 M"), Ok(2_097_152));
+        assert_eq!(parse_bytes_procfs("1 GB"), Ok(1_073_741_824));
+        assert_eq!(parse_bytes_procfs("2P"), Ok(2 * 1024_u64.pow(5)));
+        assert_eq!(parse_bytes_procfs("1 EB"), Ok(1024_u64.pow(6)));
+        assert_eq!(
+            parse_bytes_procfs("1 KiB"),
+            Err(ParseBytesError::UnknownUnit)
+        );
+    }
+
+    #[test]
+    fn test_parse_bytes_column() {
+        assert_eq!(
+            parse_bytes_column(["1 KB", " ", "2.5 MB"], parse_bytes),
+            Ok(vec![(0, 1000), (2, 2_500_000)])
+        );
+
+        assert_eq!(
+            parse_bytes_column(["4.0K", "1.5M", "12G", "2.1P"], parse_bytes_procfs),
+            Ok(vec![
+                (0, 4096),
+                (1, 1_572_864),
+                (2, 12_884_901_888),
+                (3, 2_364_389_804_369_510)
+            ])
+        );
+
+        // Closures can capture state, such as a column separator
+        let separator = '\t';
+        assert_eq!(
+            parse_bytes_column(["4.0K\t./src", "1.5M\t./target"], |line| {
+                parse_bytes_procfs(line.split(separator).next().unwrap_or(line))
+            }),
+            Ok(vec![(0, 4096), (1, 1_572_864)])
+        );
+
+        assert_eq!(parse_bytes_column([], parse_bytes), Ok(vec![]));
+
+        let errors = parse_bytes_column(["1 KB", "oops", "2 MB", "1 XB"], parse_bytes).unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                ColumnParseError {
+                    index: 1,
+                    error: ParseBytesError::InvalidNumber
+                },
+                ColumnParseError {
+                    index: 3,
+                    error: ParseBytesError::UnknownUnit
+                },
+            ]
+        );
+        assert_eq!(errors[0].to_string(), "value 1: invalid number");
+    }
+
+    #[test]
+    fn test_parse_preserving_unit() {
+        let prettified: PrettyBytes = "2048 KB".parse().unwrap();
+        assert_eq!(prettified.to_string(), "2048 KB");
+        assert_eq!(prettified, 2_048_000_u64);
+
+        assert_eq!(
+            "1.5mb".parse::<PrettyBytes>().unwrap().to_string(),
+            "1.5 MB"
+        );
+        assert_eq!(
+            "0x10 KB".parse::<PrettyBytes>().unwrap().to_string(),
+            "16 KB"
+        );
+        assert_eq!("512".parse::<PrettyBytes>().unwrap().to_string(), "512 B");
+        assert_eq!(
+            "1 KiB".parse::<PrettyBytes>(),
+            Err(ParseBytesError::UnknownUnit)
+        );
+        assert_eq!(
+            "20 EB".parse::<PrettyBytes>(),
+            Err(ParseBytesError::OutOfRange)
+        );
+
+        assert_eq!(
+            "1.5 B".parse::<PrettyBytes>(),
+            Err(ParseBytesError::InvalidNumber)
+        );
+        assert_eq!("1.0 B".parse::<PrettyBytes>().unwrap(), 1_u64);
+        assert_eq!(
+            "0.5".parse::<PrettyBytesBinary>(),
+            Err(ParseBytesError::InvalidNumber)
+        );
+        assert_eq!("1.0005 KB".parse::<PrettyBytes>().unwrap(), 1001_u64);
+
+        let prettified: PrettyBytes = "-1 MB".parse().unwrap();
+        assert_eq!(prettified.to_string(), "-1 MB");
+        assert!(prettified < 0_u64);
+        assert_eq!(
+            "- 1 MB".parse::<PrettyBytes>(),
+            Err(ParseBytesError::InvalidNumber)
+        );
+
+        // Signed values round-trip through their `Display` output
+        for num in [-3_564_234, -1, 0, 1_500, i64::MIN] {
+            let prettified = crate::pretty_bytes_signed(num, Some(2)).to_string();
+            assert_eq!(
+                prettified.parse::<PrettyBytes>().unwrap().to_string(),
+                prettified
+            );
+
+            let prettified = crate::pretty_bytes_signed_binary(num, Some(2)).to_string();
+            assert_eq!(
+                prettified.parse::<PrettyBytesBinary>().unwrap().to_string(),
+                prettified
+            );
+        }
+
+        let prettified: PrettyBytesBinary = "2048 KiB".parse().unwrap();
+        assert_eq!(prettified.to_string(), "2048 KiB");
+        assert_eq!(prettified, 2_097_152_u64);
+
+        assert_eq!(
+            "1 KB".parse::<PrettyBytesBinary>(),
+            Err(ParseBytesError::UnknownUnit)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use crate::pretty_bytes;
+
+/// Struct that keeps track of how many bytes have been used out of a limit
+///
+/// Usage can be recorded with `+=` and released with `-=`
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::ByteQuota;
+/// let mut quota = ByteQuota::new(0, 5_000_000_000);
+/// quota += 1_200_000_000;
+///
+/// assert_eq!(quota.remaining(), 3_800_000_000);
+/// assert!(!quota.is_exceeded());
+/// assert_eq!(quota.to_string(), "1.2 GB of 5 GB (24%)");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ByteQuota {
+    used: u64,
+    limit: u64,
+}
+
+impl ByteQuota {
+    /// Create a quota with `used` bytes already used out of `limit`
+    pub const fn new(used: u64, limit: u64) -> Self {
+        Self { used, limit }
+    }
+
+    /// Get the number of bytes used
+    #[must_use]
+    pub const fn used(&self) -> u64 {
+        self.used
+    }
+
+    /// Get the maximum number of bytes that can be used
+    #[must_use]
+    pub const fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Get the number of bytes that can still be used before reaching the limit
+    #[must_use]
+    pub const fn remaining(&self) -> u64 {
+        self.limit.saturating_sub(self.used)
+    }
+
+    /// Get the percentage of the limit that has been used
+    ///
+    /// A zero limit is always reported as 100% used
+    #[must_use]
+    // Byte counts only lose precision above 2^53 bytes, which barely affects a percentage
+    #[allow(clippy::cast_precision_loss)]
+    pub fn percent_used(&self) -> f64 {
+        if self.limit == 0 {
+            return 100.;
+        }
+
+        self.used as f64 / self.limit as f64 * 100.
+    }
+
+    /// Check whether more bytes have been used than the limit allows
+    #[must_use]
+    pub const fn is_exceeded(&self) -> bool {
+        self.used > self.limit
+    }
+}
+
+impl std::ops::AddAssign<u64> for ByteQuota {
+    fn add_assign(&mut self, rhs: u64) {
+        self.used = self.used.saturating_add(rhs);
+    }
+}
+
+impl std::ops::SubAssign<u64> for ByteQuota {
+    fn sub_assign(&mut self, rhs: u64) {
+        self.used = self.used.saturating_sub(rhs);
+    }
+}
+
+impl std::fmt::Display for ByteQuota {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Rounded down, so that 100% is only shown once the limit has actually been reached
+        let percent = if self.limit == 0 {
+            100
+        } else {
+            u128::from(self.used) * 100 / u128::from(self.limit)
+        };
+
+        write!(
+            f,
+            "{} of {} ({percent}%)",
+            pretty_bytes(self.used, Some(1)),
+            pretty_bytes(self.limit, Some(1)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // All of these percentages are exactly representable
+    #[allow(clippy::float_cmp)]
+    fn test_byte_quota() {
+        let mut quota = ByteQuota::new(0, 5_000_000_000);
+        assert_eq!(quota.to_string(), "0 B of 5 GB (0%)");
+
+        quota += 1_200_000_000;
+        assert_eq!(quota.used(), 1_200_000_000);
+        assert_eq!(quota.remaining(), 3_800_000_000);
+        assert_eq!(quota.percent_used(), 24.);
+        assert!(!quota.is_exceeded());
+        assert_eq!(quota.to_string(), "1.2 GB of 5 GB (24%)");
+
+        quota += 4_000_000_000;
+        assert_eq!(quota.remaining(), 0);
+        assert!(quota.is_exceeded());
+        assert_eq!(quota.to_string(), "5.2 GB of 5 GB (104%)");
+
+        quota -= 6_000_000_000;
+        assert_eq!(quota.used(), 0);
+
+        quota += u64::MAX;
+        quota += 1;
+        assert_eq!(quota.used(), u64::MAX);
+
+        assert_eq!(ByteQuota::new(0, 0).percent_used(), 100.);
+
+        // The displayed percentage only reaches 100% at the limit
+        assert_eq!(ByteQuota::new(996, 1000).to_string(), "996 B of 1 KB (99%)");
+        assert_eq!(
+            ByteQuota::new(1000, 1000).to_string(),
+            "1 KB of 1 KB (100%)"
+        );
+        assert_eq!(ByteQuota::new(0, 0).to_string(), "0 B of 0 B (100%)");
+    }
+}
@@ -0,0 +1,270 @@
+use std::time::Duration;
+
+use crate::{
+    decimal::pretty_bytes_float, pretty_bytes, pretty_duration, PrettyBytes, PrettyDuration,
+};
+
+/// Struct that represents a prettified transfer rate (base-10)
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyRate {
+    bytes: PrettyBytes,
+    per: TimeBase,
+}
+
+impl std::fmt::Display for PrettyRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let per = match self.per {
+            TimeBase::Second => "s",
+            TimeBase::Minute => "min",
+            TimeBase::Hour => "h",
+        };
+
+        write!(f, "{}/{per}", self.bytes)
+    }
+}
+
+/// Time bases that a rate can be expressed in
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimeBase {
+    Second,
+    Minute,
+    Hour,
+}
+
+impl TimeBase {
+    const UNITS: [Self; 3] = [Self::Second, Self::Minute, Self::Hour];
+
+    const fn secs(self) -> f64 {
+        match self {
+            Self::Second => 1.,
+            Self::Minute => 60.,
+            Self::Hour => 3600.,
+        }
+    }
+}
+
+// Byte counts only lose precision above 2^53 bytes, which is far more than any real transfer
+#[allow(clippy::cast_precision_loss)]
+fn bytes_per_second(num: u64, duration: Duration) -> f64 {
+    let secs = duration.as_secs_f64();
+
+    if secs == 0. {
+        0.
+    } else {
+        num as f64 / secs
+    }
+}
+
+/// Calculate the rate at which a number of bytes was transferred over a duration, and convert it to a "prettified" version
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB). A zero duration is reported as a rate of 0 B/s.
+///
+/// ## Example
+/// ```
+/// # use std::time::Duration;
+/// # use pretty_bytes_typed::pretty_rate;
+/// let prettified = pretty_rate(5_000_000, Duration::from_secs(2), None);
+/// assert_eq!(prettified.to_string(), "2.5 MB/s");
+/// ```
+pub fn pretty_rate(num: u64, duration: Duration, round_places: Option<u8>) -> PrettyRate {
+    pretty_rate_per(num, duration, TimeBase::Second, round_places)
+}
+
+/// Calculate the rate at which a number of bytes was transferred over a duration, and convert it to a "prettified" version using the given time base
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB)
+///
+/// ## Example
+/// ```
+/// # use std::time::Duration;
+/// # use pretty_bytes_typed::{pretty_rate_per, TimeBase};
+/// let prettified = pretty_rate_per(5_000_000, Duration::from_secs(2), TimeBase::Minute, None);
+/// assert_eq!(prettified.to_string(), "150 MB/min");
+/// ```
+pub fn pretty_rate_per(
+    num: u64,
+    duration: Duration,
+    per: TimeBase,
+    round_places: Option<u8>,
+) -> PrettyRate {
+    PrettyRate {
+        bytes: pretty_bytes_float(bytes_per_second(num, duration) * per.secs(), round_places),
+        per,
+    }
+}
+
+/// Calculate the rate at which a number of bytes was transferred over a duration, and convert it to a "prettified" version, choosing the time base automatically
+///
+/// Uses the shortest time base in which at least 1 KB is transferred, so slow transfers are shown per minute or per hour. A rate of 0 is shown per second.
+///
+/// ## Example
+/// ```
+/// # use std::time::Duration;
+/// # use pretty_bytes_typed::pretty_rate_auto;
+/// let prettified = pretty_rate_auto(2_000_000, Duration::from_secs(1), None);
+/// assert_eq!(prettified.to_string(), "2 MB/s");
+///
+/// let prettified = pretty_rate_auto(4_200, Duration::from_secs(3600), None);
+/// assert_eq!(prettified.to_string(), "4.2 KB/h");
+/// ```
+pub fn pretty_rate_auto(num: u64, duration: Duration, round_places: Option<u8>) -> PrettyRate {
+    let per_second = bytes_per_second(num, duration);
+
+    let per = if per_second == 0. {
+        TimeBase::Second
+    } else {
+        TimeBase::UNITS
+            .into_iter()
+            .find(|per| per_second * per.secs() >= 1000.)
+            .unwrap_or(TimeBase::Hour)
+    };
+
+    PrettyRate {
+        bytes: pretty_bytes_float(per_second * per.secs(), round_places),
+        per,
+    }
+}
+
+/// Struct that summarizes a transfer: the amount transferred, how long it took, and the average rate
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyTransferSummary {
+    bytes: PrettyBytes,
+    duration: PrettyDuration,
+    // There's no meaningful rate for a transfer that took no time at all
+    rate: Option<PrettyRate>,
+}
+
+impl std::fmt::Display for PrettyTransferSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} in {}", self.bytes, self.duration)?;
+
+        if let Some(rate) = &self.rate {
+            write!(f, " ({rate})")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Summarize a transfer of a number of bytes over a duration
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB), and rounds both the size and the rate to `round_places`.
+/// The rate is left out for a zero duration, since there's no meaningful rate for an instant transfer.
+///
+/// ## Example
+/// ```
+/// # use std::time::Duration;
+/// # use pretty_bytes_typed::pretty_transfer_summary;
+/// let summary = pretty_transfer_summary(487_000_000, Duration::from_secs(192), Some(1));
+/// assert_eq!(format!("downloaded {summary}"), "downloaded 487 MB in 3 m 12 s (2.5 MB/s)");
+/// ```
+pub fn pretty_transfer_summary(
+    num: u64,
+    duration: Duration,
+    round_places: Option<u8>,
+) -> PrettyTransferSummary {
+    PrettyTransferSummary {
+        bytes: pretty_bytes(num, round_places),
+        duration: pretty_duration(duration),
+        rate: (!duration.is_zero()).then(|| pretty_rate(num, duration, round_places)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_rate() {
+        assert_eq!(
+            pretty_rate(5_000_000, Duration::from_secs(2), None).to_string(),
+            "2.5 MB/s"
+        );
+
+        assert_eq!(
+            pretty_rate(1, Duration::from_secs(2), None).to_string(),
+            "0.5 B/s"
+        );
+
+        assert_eq!(
+            pretty_rate(1_000_000, Duration::from_millis(250), None).to_string(),
+            "4 MB/s"
+        );
+
+        assert_eq!(
+            pretty_rate(10_000, Duration::from_secs(3), Some(2)).to_string(),
+            "3.33 KB/s"
+        );
+
+        assert_eq!(
+            pretty_rate(1_000, Duration::ZERO, None).to_string(),
+            "0 B/s"
+        );
+    }
+
+    #[test]
+    fn test_pretty_rate_time_base() {
+        assert_eq!(
+            pretty_rate_per(1_000, Duration::from_secs(1), TimeBase::Second, None).to_string(),
+            "1 KB/s"
+        );
+
+        assert_eq!(
+            pretty_rate_per(1_000, Duration::from_secs(1), TimeBase::Minute, None).to_string(),
+            "60 KB/min"
+        );
+
+        assert_eq!(
+            pretty_rate_per(1_000, Duration::from_secs(1), TimeBase::Hour, None).to_string(),
+            "3.6 MB/h"
+        );
+
+        assert_eq!(
+            pretty_rate_auto(1_000, Duration::from_secs(1), None).to_string(),
+            "1 KB/s"
+        );
+
+        assert_eq!(
+            pretty_rate_auto(500, Duration::from_secs(1), None).to_string(),
+            "30 KB/min"
+        );
+
+        assert_eq!(
+            pretty_rate_auto(10, Duration::from_secs(100), None).to_string(),
+            "360 B/h"
+        );
+
+        assert_eq!(
+            pretty_rate_auto(0, Duration::from_secs(1), None).to_string(),
+            "0 B/s"
+        );
+
+        assert_eq!(
+            pretty_rate_auto(1_000, Duration::ZERO, None).to_string(),
+            "0 B/s"
+        );
+    }
+
+    #[test]
+    fn test_pretty_transfer_summary() {
+        assert_eq!(
+            pretty_transfer_summary(487_000_000, Duration::from_secs(192), Some(1)).to_string(),
+            "487 MB in 3 m 12 s (2.5 MB/s)"
+        );
+
+        assert_eq!(
+            pretty_transfer_summary(512, Duration::from_millis(100), None).to_string(),
+            "512 B in 100 ms (5.12 KB/s)"
+        );
+
+        assert_eq!(
+            pretty_transfer_summary(1, Duration::ZERO, None).to_string(),
+            "1 B in 0 ms"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+/// Styles for displaying negative byte values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignStyle {
+    /// Show negative values with a leading minus sign, like "-1.2 MB"
+    Minus,
+    /// Show negative values in parentheses, like "(1.2 MB)", as used in accounting and billing reports
+    Parentheses,
+}
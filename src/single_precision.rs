@@ -0,0 +1,149 @@
+// Everything here only uses `f32`, so that using these functions doesn't pull in any double-precision float code
+
+use crate::{ByteValues, ByteValuesBinary};
+
+/// Struct that represents prettified byte values (base-10), using an `f32` for the mantissa
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesF32 {
+    num: f32,
+    suffix: ByteValues,
+}
+
+impl std::fmt::Display for PrettyBytesF32 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:?}", self.num, self.suffix)
+    }
+}
+
+/// Struct that represents prettified byte values (base-2), using an `f32` for the mantissa
+#[derive(Debug, PartialEq, Clone)]
+#[must_use]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PrettyBytesBinaryF32 {
+    num: f32,
+    suffix: ByteValuesBinary,
+}
+
+impl std::fmt::Display for PrettyBytesBinaryF32 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:?}", self.num, self.suffix)
+    }
+}
+
+fn round_f32(num: f32, round_places: u8) -> f32 {
+    // Past this many places, rounding can't change the value, and the exponent could overflow to infinity
+    if u32::from(round_places) > f32::DIGITS {
+        return num;
+    }
+
+    let exponent = 10_f32.powi(round_places.into());
+    (num * exponent).round() / exponent
+}
+
+/// Convert a byte value to a "prettified" version, doing the math in `f32` instead of `f64`
+///
+/// Converts using base-10 byte suffixes (KB, MB, GB). This is much cheaper on targets without hardware double-precision support,
+/// but an `f32` only holds about 7 significant digits, so unrounded values can show slightly different digits than [`pretty_bytes`](crate::pretty_bytes).
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_f32;
+/// let prettified = pretty_bytes_f32(3_564_234, Some(2));
+/// assert_eq!(prettified.to_string(), "3.56 MB");
+/// ```
+// Values are expected to lose precision, since that's the point of using an f32
+#[allow(clippy::cast_precision_loss)]
+pub fn pretty_bytes_f32(num: u64, round_places: Option<u8>) -> PrettyBytesF32 {
+    // Special handling for 0, because you can't use log10 on it
+    if num == 0 {
+        return PrettyBytesF32 {
+            num: 0.,
+            suffix: ByteValues::B,
+        };
+    }
+
+    let exponent = std::cmp::min((num.ilog10() / 3) as usize, ByteValues::UNITS.len() - 1);
+
+    let mut num = num as f32 / 1000_f32.powi(exponent as i32);
+
+    if let Some(round_places) = round_places {
+        num = round_f32(num, round_places);
+    }
+
+    PrettyBytesF32 {
+        num,
+        suffix: ByteValues::UNITS[exponent],
+    }
+}
+
+/// Convert a byte value to a "prettified" version, doing the math in `f32` instead of `f64`
+///
+/// Converts using base-2 byte suffixes (KiB, MiB, GiB). This is much cheaper on targets without hardware double-precision support,
+/// but an `f32` only holds about 7 significant digits, so unrounded values can show slightly different digits than [`pretty_bytes_binary`](crate::pretty_bytes_binary).
+///
+/// ## Example
+/// ```
+/// # use pretty_bytes_typed::pretty_bytes_binary_f32;
+/// let prettified = pretty_bytes_binary_f32(3_195_498, Some(2));
+/// assert_eq!(prettified.to_string(), "3.05 MiB");
+/// ```
+// Values are expected to lose precision, since that's the point of using an f32
+#[allow(clippy::cast_precision_loss)]
+pub fn pretty_bytes_binary_f32(num: u64, round_places: Option<u8>) -> PrettyBytesBinaryF32 {
+    // Special handling for 0, because you can't use log on it
+    if num == 0 {
+        return PrettyBytesBinaryF32 {
+            num: 0.,
+            suffix: ByteValuesBinary::B,
+        };
+    }
+
+    let exponent = std::cmp::min(num.ilog(1024) as usize, ByteValuesBinary::UNITS.len() - 1);
+
+    let mut num = num as f32 / 1024_f32.powi(exponent as i32);
+
+    if let Some(round_places) = round_places {
+        num = round_f32(num, round_places);
+    }
+
+    PrettyBytesBinaryF32 {
+        num,
+        suffix: ByteValuesBinary::UNITS[exponent],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_bytes_f32() {
+        assert_eq!(pretty_bytes_f32(0, None).to_string(), "0 B");
+        assert_eq!(pretty_bytes_f32(5_430, None).to_string(), "5.43 KB");
+        assert_eq!(pretty_bytes_f32(3_564_234, Some(2)).to_string(), "3.56 MB");
+        assert_eq!(pretty_bytes_f32(u64::MAX, Some(2)).to_string(), "18.45 EB");
+
+        // Rounding past the precision of an f32 leaves the value untouched, instead of overflowing
+        assert_eq!(pretty_bytes_f32(1_234, Some(40)).to_string(), "1.234 KB");
+        assert_eq!(
+            pretty_bytes_f32(1_234, Some(u8::MAX)).to_string(),
+            "1.234 KB"
+        );
+    }
+
+    #[test]
+    fn test_pretty_bytes_binary_f32() {
+        assert_eq!(pretty_bytes_binary_f32(0, None).to_string(), "0 B");
+        assert_eq!(
+            pretty_bytes_binary_f32(1_048_576, None).to_string(),
+            "1 MiB"
+        );
+        assert_eq!(
+            pretty_bytes_binary_f32(3_195_498, Some(2)).to_string(),
+            "3.05 MiB"
+        );
+        assert_eq!(pretty_bytes_binary_f32(1024, Some(40)).to_string(), "1 KiB");
+    }
+}
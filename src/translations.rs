@@ -0,0 +1,225 @@
+/// Languages that unit names can be translated into
+///
+/// Both abbreviated unit names (like "MB" or "Mo") and long names (like "Megabyte") are available.
+/// The decimal separator is also changed for languages that use a comma.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    English,
+    French,
+    German,
+    Russian,
+}
+
+impl Language {
+    /// Character used to separate the integer and fractional parts of a number
+    pub(crate) const fn decimal_separator(self) -> char {
+        match self {
+            Self::English => '.',
+            Self::French | Self::German | Self::Russian => ',',
+        }
+    }
+
+    /// Format a mantissa using the decimal separator for this language
+    pub(crate) fn format_number(self, num: f64) -> String {
+        num.to_string()
+            .replace('.', &self.decimal_separator().to_string())
+    }
+
+    /// Base-10 unit names, ordered from B to EB
+    pub(crate) const fn decimal_units(self) -> [&'static str; 7] {
+        match self {
+            Self::English => ["B", "KB", "MB", "GB", "TB", "PB", "EB"],
+            Self::French => ["o", "ko", "Mo", "Go", "To", "Po", "Eo"],
+            Self::German => ["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+            Self::Russian => ["Б", "кБ", "МБ", "ГБ", "ТБ", "ПБ", "ЭБ"],
+        }
+    }
+
+    /// Base-2 unit names, ordered from B to EiB
+    pub(crate) const fn binary_units(self) -> [&'static str; 7] {
+        match self {
+            Self::English | Self::German => ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            Self::French => ["o", "Kio", "Mio", "Gio", "Tio", "Pio", "Eio"],
+            Self::Russian => ["Б", "КиБ", "МиБ", "ГиБ", "ТиБ", "ПиБ", "ЭиБ"],
+        }
+    }
+
+    /// Long base-10 unit names (without any plural ending), ordered from B to EB
+    pub(crate) const fn decimal_long_units(self) -> [&'static str; 7] {
+        match self {
+            Self::English => [
+                "byte", "kilobyte", "megabyte", "gigabyte", "terabyte", "petabyte", "exabyte",
+            ],
+            Self::French => [
+                "octet",
+                "kilooctet",
+                "mégaoctet",
+                "gigaoctet",
+                "téraoctet",
+                "pétaoctet",
+                "exaoctet",
+            ],
+            Self::German => [
+                "Byte", "Kilobyte", "Megabyte", "Gigabyte", "Terabyte", "Petabyte", "Exabyte",
+            ],
+            Self::Russian => [
+                "байт",
+                "килобайт",
+                "мегабайт",
+                "гигабайт",
+                "терабайт",
+                "петабайт",
+                "эксабайт",
+            ],
+        }
+    }
+
+    /// Long base-2 unit names (without any plural ending), ordered from B to EiB
+    pub(crate) const fn binary_long_units(self) -> [&'static str; 7] {
+        match self {
+            Self::English => [
+                "byte", "kibibyte", "mebibyte", "gibibyte", "tebibyte", "pebibyte", "exbibyte",
+            ],
+            Self::French => [
+                "octet",
+                "kibioctet",
+                "mébioctet",
+                "gibioctet",
+                "tébioctet",
+                "pébioctet",
+                "exbioctet",
+            ],
+            Self::German => [
+                "Byte", "Kibibyte", "Mebibyte", "Gibibyte", "Tebibyte", "Pebibyte", "Exbibyte",
+            ],
+            Self::Russian => [
+                "байт",
+                "кибибайт",
+                "мебибайт",
+                "гибибайт",
+                "тебибайт",
+                "пебибайт",
+                "эксбибайт",
+            ],
+        }
+    }
+
+    /// Ending to add to a long unit name so that it agrees with the number in front of it
+    // Only a mantissa that is displayed as exactly 1 reads as singular
+    #[allow(clippy::float_cmp)]
+    pub(crate) fn plural_ending(self, num: f64) -> &'static str {
+        let num = num.abs();
+
+        match self {
+            Self::English if num == 1. => "",
+            // French only uses the plural from 2 onwards, so "1,5 mégaoctet" is singular
+            Self::French if num < 2. => "",
+            Self::English | Self::French => "s",
+            // "Byte" is the same in the plural
+            Self::German => "",
+            // Fractions always take the genitive singular
+            Self::Russian if num.fract() != 0. => "а",
+            Self::Russian => {
+                let num = num as u64;
+                if (2..=4).contains(&(num % 10)) && !(12..=14).contains(&(num % 100)) {
+                    "а"
+                } else {
+                    ""
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{pretty_bytes, pretty_bytes_binary, pretty_bytes_signed};
+
+    use super::*;
+
+    #[test]
+    fn test_translations() {
+        assert_eq!(
+            pretty_bytes(3_564_234, Some(2)).to_string_in(Language::English),
+            "3.56 MB"
+        );
+
+        assert_eq!(
+            pretty_bytes(3_564_234, Some(2)).to_string_in(Language::French),
+            "3,56 Mo"
+        );
+
+        assert_eq!(
+            pretty_bytes(5_430, None).to_string_in(Language::German),
+            "5,43 kB"
+        );
+
+        assert_eq!(
+            pretty_bytes_signed(-2_000_000, None).to_string_in(Language::Russian),
+            "-2 МБ"
+        );
+
+        assert_eq!(
+            pretty_bytes_binary(1_048_576, None).to_string_in(Language::French),
+            "1 Mio"
+        );
+
+        assert_eq!(
+            pretty_bytes_binary(1024_u64.pow(3), None).to_string_in(Language::Russian),
+            "1 ГиБ"
+        );
+
+        assert_eq!(
+            pretty_bytes_signed(-1_500, None).to_string_in(Language::Russian),
+            "-1,5 кБ"
+        );
+    }
+
+    #[test]
+    fn test_long_translations() {
+        assert_eq!(
+            pretty_bytes(3_564_234, Some(2)).to_string_in_long(Language::German),
+            "3,56 Megabyte"
+        );
+
+        assert_eq!(
+            pretty_bytes(1_000_000, None).to_string_in_long(Language::English),
+            "1 megabyte"
+        );
+
+        assert_eq!(
+            pretty_bytes(2_500, None).to_string_in_long(Language::English),
+            "2.5 kilobytes"
+        );
+
+        assert_eq!(
+            pretty_bytes(1_500_000, None).to_string_in_long(Language::French),
+            "1,5 mégaoctet"
+        );
+
+        assert_eq!(
+            pretty_bytes(2_000_000, None).to_string_in_long(Language::French),
+            "2 mégaoctets"
+        );
+
+        assert_eq!(
+            pretty_bytes_binary(1024_u64.pow(2) * 3, None).to_string_in_long(Language::Russian),
+            "3 мебибайта"
+        );
+
+        assert_eq!(
+            pretty_bytes(21_000_000_000, None).to_string_in_long(Language::Russian),
+            "21 гигабайт"
+        );
+
+        assert_eq!(
+            pretty_bytes(12_000, None).to_string_in_long(Language::Russian),
+            "12 килобайт"
+        );
+
+        assert_eq!(
+            pretty_bytes_signed(-1_500, None).to_string_in_long(Language::Russian),
+            "-1,5 килобайта"
+        );
+    }
+}
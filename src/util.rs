@@ -1,4 +1,155 @@
+use crate::SignStyle;
+
 pub fn round_float(num: f64, round_places: u8) -> f64 {
+    // Past this many places, rounding can't change the value, and the exponent could overflow to infinity
+    if u32::from(round_places) > f64::DIGITS {
+        return num;
+    }
+
     let exponent = 10_f64.powi(round_places.into());
     (num * exponent).round() / exponent
 }
+
+/// Format each value so that the decimal points line up, padding the integer part with spaces and the fractional part with zeros
+pub fn align_decimal_points<U: std::fmt::Debug>(
+    values: impl Iterator<Item = (f64, U)>,
+) -> Vec<String> {
+    let parts: Vec<(String, String, U)> = values
+        .map(|(num, unit)| {
+            let num = num.to_string();
+            let (int, frac) = num.split_once('.').unwrap_or((&num, ""));
+            (int.to_owned(), frac.to_owned(), unit)
+        })
+        .collect();
+
+    let int_width = parts.iter().map(|(int, _, _)| int.len()).max().unwrap_or(0);
+    let frac_width = parts
+        .iter()
+        .map(|(_, frac, _)| frac.len())
+        .max()
+        .unwrap_or(0);
+
+    parts
+        .into_iter()
+        .map(|(int, frac, unit)| {
+            if frac_width == 0 {
+                format!("{int:>int_width$} {unit:?}")
+            } else {
+                format!("{int:>int_width$}.{frac:0<frac_width$} {unit:?}")
+            }
+        })
+        .collect()
+}
+
+/// Check whether the `Display` output of a value is equal to a string, without allocating
+pub fn display_eq(value: &impl std::fmt::Display, other: &str) -> bool {
+    struct Comparer<'a> {
+        remaining: &'a str,
+    }
+
+    impl std::fmt::Write for Comparer<'_> {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.remaining = self.remaining.strip_prefix(s).ok_or(std::fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    let mut comparer = Comparer { remaining: other };
+    std::fmt::Write::write_fmt(&mut comparer, format_args!("{value}")).is_ok()
+        && comparer.remaining.is_empty()
+}
+
+/// Write each non-zero part followed by its unit, from the largest unit to the smallest
+pub fn write_breakdown<U: std::fmt::Debug>(
+    f: &mut std::fmt::Formatter<'_>,
+    parts: &[u16],
+    units: &[U],
+) -> std::fmt::Result {
+    let mut first = true;
+    for (part, unit) in parts
+        .iter()
+        .zip(units)
+        .rev()
+        .filter(|(part, _)| **part != 0)
+    {
+        if !first {
+            f.write_str(" ")?;
+        }
+        first = false;
+        write!(f, "{part} {unit:?}")?;
+    }
+
+    if first {
+        write!(f, "0 {:?}", units[0])?;
+    }
+
+    Ok(())
+}
+
+/// Find the shortest fixed-point mantissa (as digits and a number of decimal places) of `num / unit` that still rounds back to exactly `num` bytes
+pub fn canonical_mantissa(num: u64, unit: u64) -> (u128, u8) {
+    let num = u128::from(num);
+    let unit = u128::from(unit);
+
+    // 19 decimal places is always enough to tell apart single bytes, even at EiB scale
+    for places in 0..19 {
+        let scale = 10_u128.pow(places);
+        let mantissa = (num * scale + unit / 2) / unit;
+
+        if (mantissa * unit + scale / 2) / scale == num {
+            return (mantissa, places as u8);
+        }
+    }
+
+    let scale = 10_u128.pow(19);
+    ((num * scale + unit / 2) / unit, 19)
+}
+
+/// Write a fixed-point mantissa with the decimal point inserted `places` digits from the right
+pub fn write_fixed_point(
+    f: &mut std::fmt::Formatter<'_>,
+    mantissa: u128,
+    places: u8,
+) -> std::fmt::Result {
+    if places == 0 {
+        return write!(f, "{mantissa}");
+    }
+
+    let scale = 10_u128.pow(places.into());
+    let width = places.into();
+
+    write!(f, "{}.{:0width$}", mantissa / scale, mantissa % scale)
+}
+
+/// Pick the number of decimal places based on the magnitude of the mantissa, like many file managers do
+pub fn adaptive_places(num: f64) -> u8 {
+    match num.abs() {
+        num if num < 10. => 2,
+        num if num < 100. => 1,
+        _ => 0,
+    }
+}
+
+/// Format a value and unit, showing negative values in the given style
+pub fn format_with_sign<U: std::fmt::Debug>(num: f64, unit: U, style: SignStyle) -> String {
+    match style {
+        SignStyle::Parentheses if num < 0. => {
+            format!("({} {unit:?})", -num)
+        }
+        _ => format!("{num} {unit:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // Rounding past the precision of `f64` leaves the value untouched
+    #[allow(clippy::float_cmp)]
+    fn test_round_float() {
+        assert_eq!(round_float(1.234, 2), 1.23);
+        assert_eq!(round_float(1.234, 40), 1.234);
+        assert_eq!(round_float(1.234, u8::MAX), 1.234);
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "derive")]
+
+use pretty_bytes_typed::PrettyBytesFields;
+
+#[derive(PrettyBytesFields)]
+struct DiskUsage {
+    name: &'static str,
+    #[pretty(binary, round = 2)]
+    used: u64,
+    #[pretty]
+    total: u64,
+    #[pretty(round = 1)]
+    cached: u32,
+    #[pretty(skip)]
+    #[allow(dead_code)]
+    inode: u64,
+}
+
+#[derive(PrettyBytesFields)]
+struct Generic<T: std::fmt::Display> {
+    label: T,
+    #[pretty(round = 0)]
+    size: u64,
+}
+
+#[test]
+fn test_derive_pretty_bytes_fields() {
+    let usage = DiskUsage {
+        name: "/dev/sda1",
+        used: 3_195_498,
+        total: 5_000_000_000,
+        cached: 55_700,
+        inode: 12,
+    };
+
+    assert_eq!(
+        usage.to_string(),
+        "name: /dev/sda1, used: 3.05 MiB, total: 5 GB, cached: 55.7 KB"
+    );
+
+    let generic = Generic {
+        label: 7,
+        size: 1_500,
+    };
+
+    assert_eq!(generic.to_string(), "label: 7, size: 2 KB");
+}
+
+#[cfg(feature = "serde")]
+#[derive(pretty_bytes_typed::PrettyBytesSerialize)]
+struct Report {
+    name: &'static str,
+    #[pretty(binary, round = 2)]
+    used: u64,
+    #[pretty(skip)]
+    #[allow(dead_code)]
+    inode: u64,
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_derive_pretty_bytes_serialize() {
+    let report = Report {
+        name: "/dev/sda1",
+        used: 3_195_498,
+        inode: 12,
+    };
+
+    assert_eq!(
+        serde_json::to_string(&report).unwrap(),
+        r#"{"name":"/dev/sda1","used":{"num":3.05,"suffix":"MiB"}}"#
+    );
+}
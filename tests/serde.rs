@@ -0,0 +1,37 @@
+#![cfg(feature = "serde")]
+
+use pretty_bytes_typed::{
+    pretty_bytes, pretty_bytes_binary, pretty_bytes_signed, PrettyBytes, PrettyBytesBinary,
+};
+
+#[test]
+fn test_serde_round_trip() {
+    // Only the prettified value is serialized, never the full number of bytes
+    let prettified = pretty_bytes(u64::MAX, Some(2));
+    let json = serde_json::to_string(&prettified).unwrap();
+    assert_eq!(json, r#"{"num":18.45,"suffix":"EB"}"#);
+    assert_eq!(
+        serde_json::from_str::<PrettyBytes>(&json).unwrap(),
+        prettified
+    );
+
+    let prettified = pretty_bytes_binary(2_621_440, None);
+    let json = serde_json::to_string(&prettified).unwrap();
+    assert_eq!(json, r#"{"num":2.5,"suffix":"MiB"}"#);
+    assert_eq!(
+        serde_json::from_str::<PrettyBytesBinary>(&json).unwrap(),
+        prettified
+    );
+
+    // The number of bytes is rebuilt from the prettified value
+    let deserialized: PrettyBytes = serde_json::from_str(r#"{"num":2.0,"suffix":"MB"}"#).unwrap();
+    assert_eq!(deserialized, 2_000_000_u64);
+    assert_eq!(
+        format!("{deserialized:?}"),
+        "PrettyBytes { pretty: 2 MB, bytes: 2000000 }"
+    );
+
+    let deserialized: PrettyBytes = serde_json::from_str(r#"{"num":-1.5,"suffix":"KB"}"#).unwrap();
+    assert_eq!(deserialized, pretty_bytes_signed(-1_500, None));
+    assert!(deserialized < 0_u64);
+}